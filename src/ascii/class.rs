@@ -0,0 +1,345 @@
+// textos::ascii::class
+//
+//! ASCII character classes.
+//
+
+use crate::unicode::char::{Char7, Char8};
+use core::mem::size_of;
+
+/// A set of ASCII character classes.
+///
+/// The class of each ASCII byte is precomputed in a constant table,
+/// so checking it is a single lookup.
+///
+/// # Examples
+/// ```
+/// use textos::ascii::AsciiClass;
+///
+/// assert![AsciiClass::of(b'f').contains(AsciiClass::HEX_DIGIT | AsciiClass::TOKEN)];
+/// assert![!AsciiClass::of(b'(').contains(AsciiClass::TOKEN)];
+/// assert![AsciiClass::of(0xE9).is_empty()];
+///
+/// assert_eq![AsciiClass::TOKEN.find_mismatch(b"Content-Type: text"), Some(12)];
+/// assert![AsciiClass::URL_SAFE.matches_all(b"a_b-c.d~e")];
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AsciiClass(u16);
+
+impl AsciiClass {
+    /// No class.
+    pub const NONE: Self = Self(0);
+    /// Control characters: `0x00..=0x1F` and `0x7F`.
+    pub const CONTROL: Self = Self(1 << 0);
+    /// Whitespace: space, tab, line feed, form feed and carriage return.
+    pub const WHITESPACE: Self = Self(1 << 1);
+    /// Printable characters, including the space: `0x20..=0x7E`.
+    pub const PRINTABLE: Self = Self(1 << 2);
+    /// Graphic characters, printable without the space: `0x21..=0x7E`.
+    pub const GRAPHIC: Self = Self(1 << 3);
+    /// Punctuation: the graphic characters that are not alphanumeric.
+    pub const PUNCTUATION: Self = Self(1 << 4);
+    /// Decimal digits: `0-9`.
+    pub const DIGIT: Self = Self(1 << 5);
+    /// Hexadecimal digits: `0-9`, `a-f`, `A-F`.
+    pub const HEX_DIGIT: Self = Self(1 << 6);
+    /// Alphabetic characters: `a-z`, `A-Z`.
+    pub const ALPHABETIC: Self = Self(1 << 7);
+    /// Uppercase letters: `A-Z`.
+    pub const UPPERCASE: Self = Self(1 << 8);
+    /// Lowercase letters: `a-z`.
+    pub const LOWERCASE: Self = Self(1 << 9);
+    /// Token characters of [RFC 7230][0]: the alphanumerics
+    /// and ``!#$%&'*+-.^_`|~``.
+    ///
+    /// [0]: https://www.rfc-editor.org/rfc/rfc7230#section-3.2.6
+    pub const TOKEN: Self = Self(1 << 10);
+    /// Unreserved URL characters of [RFC 3986][0]: the alphanumerics and `-._~`.
+    ///
+    /// [0]: https://www.rfc-editor.org/rfc/rfc3986#section-2.3
+    pub const URL_SAFE: Self = Self(1 << 11);
+
+    /// The classes of each ASCII byte.
+    pub const TABLE: [Self; 128] = {
+        let mut table = [Self::NONE; 128];
+        let mut i = 0;
+        while i < 128 {
+            table[i] = Self::compute(i as u8);
+            i += 1;
+        }
+        table
+    };
+
+    /// Computes the classes of an ASCII byte.
+    const fn compute(b: u8) -> Self {
+        let alphanumeric = b.is_ascii_alphanumeric();
+        let flags = [
+            (Self::CONTROL, b.is_ascii_control()),
+            (Self::WHITESPACE, b.is_ascii_whitespace()),
+            (Self::PRINTABLE, matches![b, 0x20..=0x7E]),
+            (Self::GRAPHIC, b.is_ascii_graphic()),
+            (Self::PUNCTUATION, b.is_ascii_punctuation()),
+            (Self::DIGIT, b.is_ascii_digit()),
+            (Self::HEX_DIGIT, b.is_ascii_hexdigit()),
+            (Self::ALPHABETIC, b.is_ascii_alphabetic()),
+            (Self::UPPERCASE, b.is_ascii_uppercase()),
+            (Self::LOWERCASE, b.is_ascii_lowercase()),
+            (Self::TOKEN, alphanumeric || contains(b"!#$%&'*+-.^_`|~", b)),
+            (Self::URL_SAFE, alphanumeric || contains(b"-._~", b)),
+        ];
+        let (mut class, mut i) = (Self::NONE, 0);
+        while i < flags.len() {
+            if flags[i].1 {
+                class = class.union(flags[i].0);
+            }
+            i += 1;
+        }
+        class
+    }
+
+    /// Returns the classes of the given `byte`, which are none if it's not ASCII.
+    #[inline]
+    pub const fn of(byte: u8) -> Self {
+        if byte < 128 {
+            Self::TABLE[byte as usize]
+        } else {
+            Self::NONE
+        }
+    }
+
+    /// Returns the raw bits of the set.
+    #[inline]
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if there are no classes in the set.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all the classes of `other` are in the set.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if any of the classes of `other` is in the set.
+    #[inline]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns the set with the classes of both sets.
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    //
+
+    /// Returns `true` if all the `bytes` belong to any of the classes of the set.
+    #[inline]
+    pub fn matches_all(self, bytes: &[u8]) -> bool {
+        self.find_mismatch(bytes).is_none()
+    }
+
+    /// Returns the position of the first byte that doesn't belong
+    /// to any of the classes of the set.
+    #[inline]
+    pub fn find_mismatch(self, bytes: &[u8]) -> Option<usize> {
+        bytes.iter().position(|b| !Self::of(*b).intersects(self))
+    }
+}
+
+impl core::ops::BitOr for AsciiClass {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// Returns `true` if the `bytes` contain the `byte`.
+const fn contains(bytes: &[u8], byte: u8) -> bool {
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == byte {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/* predicates */
+
+macro_rules! impl_class_predicates {
+    ($($t:ty),+) => { $(
+        /// # ASCII classes
+        impl $t {
+            /// Returns the ASCII classes of this scalar, which are none if it's not ASCII.
+            #[inline]
+            pub const fn ascii_class(self) -> AsciiClass {
+                if self.to_u32() < 128 {
+                    AsciiClass::TABLE[self.to_u32() as usize]
+                } else {
+                    AsciiClass::NONE
+                }
+            }
+
+            /// Returns `true` if this is a printable ASCII character, including the space.
+            #[inline]
+            pub const fn is_ascii_printable(self) -> bool {
+                self.ascii_class().contains(AsciiClass::PRINTABLE)
+            }
+
+            /// Returns `true` if this is a graphic ASCII character.
+            #[inline]
+            pub const fn is_ascii_graphic(self) -> bool {
+                self.ascii_class().contains(AsciiClass::GRAPHIC)
+            }
+
+            /// Returns `true` if this is an ASCII punctuation character.
+            #[inline]
+            pub const fn is_ascii_punctuation(self) -> bool {
+                self.ascii_class().contains(AsciiClass::PUNCTUATION)
+            }
+
+            /// Returns `true` if this is an ASCII hexadecimal digit.
+            #[inline]
+            pub const fn is_ascii_hexdigit(self) -> bool {
+                self.ascii_class().contains(AsciiClass::HEX_DIGIT)
+            }
+
+            /// Returns `true` if this is a token character of RFC 7230.
+            ///
+            /// See [`AsciiClass::TOKEN`].
+            #[inline]
+            pub const fn is_ascii_token(self) -> bool {
+                self.ascii_class().contains(AsciiClass::TOKEN)
+            }
+
+            /// Returns `true` if this is an unreserved URL character of RFC 3986.
+            ///
+            /// See [`AsciiClass::URL_SAFE`].
+            #[inline]
+            pub const fn is_ascii_url_safe(self) -> bool {
+                self.ascii_class().contains(AsciiClass::URL_SAFE)
+            }
+        }
+    )+ };
+}
+impl_class_predicates![Char7, Char8];
+
+/* bulk validation */
+
+/// The high bit of each byte of a word.
+const HIGH_BITS: usize = usize::from_ne_bytes([0x80; size_of::<usize>()]);
+
+/// Returns `true` if all the `bytes` are ASCII.
+///
+/// # Examples
+/// ```
+/// use textos::ascii::is_all_ascii;
+///
+/// assert![is_all_ascii(b"plain ASCII text")];
+/// assert![!is_all_ascii("not ASCII: ñ".as_bytes())];
+/// ```
+#[inline]
+pub fn is_all_ascii(bytes: &[u8]) -> bool {
+    bytes.is_ascii()
+}
+
+/// Returns the position of the first byte that is not ASCII.
+///
+/// The bytes are checked a word at a time.
+///
+/// # Examples
+/// ```
+/// use textos::ascii::find_non_ascii;
+///
+/// assert_eq![find_non_ascii("año".as_bytes()), Some(1)];
+/// assert_eq![find_non_ascii(b"ano"), None];
+/// ```
+pub fn find_non_ascii(bytes: &[u8]) -> Option<usize> {
+    const WORD: usize = size_of::<usize>();
+    let mut chunks = bytes.chunks_exact(WORD);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        let mut word = [0; WORD];
+        word.copy_from_slice(chunk);
+        let high = usize::from_ne_bytes(word) & HIGH_BITS;
+        if high != 0 {
+            break;
+        }
+        offset += WORD;
+    }
+    // the byte in the chunk that broke the loop, or in the remainder
+    bytes[offset..]
+        .iter()
+        .position(|b| !b.is_ascii())
+        .map(|i| offset + i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table() {
+        for b in 0..128_u8 {
+            let (c, class) = (b as char, AsciiClass::of(b));
+            assert_eq![class.contains(AsciiClass::CONTROL), c.is_ascii_control()];
+            assert_eq![
+                class.contains(AsciiClass::PRINTABLE),
+                c == ' ' || c.is_ascii_graphic()
+            ];
+            assert_eq![
+                class.contains(AsciiClass::PUNCTUATION),
+                c.is_ascii_punctuation()
+            ];
+            assert_eq![class.contains(AsciiClass::HEX_DIGIT), c.is_ascii_hexdigit()];
+            assert_eq![
+                class.contains(AsciiClass::URL_SAFE),
+                c.is_ascii_alphanumeric() || "-._~".contains(c)
+            ];
+            let char7 = Char7::try_from_u8(b).unwrap();
+            assert_eq![char7.ascii_class(), class];
+            assert_eq![char7.to_char8().ascii_class(), class];
+            assert_eq![
+                class.contains(AsciiClass::TOKEN),
+                c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c)
+            ];
+        }
+        assert_eq![AsciiClass::TOKEN.find_mismatch(b"GET"), None];
+        assert_eq![AsciiClass::TOKEN.find_mismatch(b"a,b"), Some(1)];
+        assert![!Char8::try_from('é').unwrap().is_ascii_printable()];
+    }
+
+    #[test]
+    fn const_lookups() {
+        const LOOKUPS: [bool; 3] = [
+            AsciiClass::of(b'~').contains(AsciiClass::TOKEN),
+            Char7::new_unchecked(b'7').is_ascii_hexdigit(),
+            AsciiClass::TABLE[0x80 - 1].contains(AsciiClass::CONTROL),
+        ];
+        assert_eq![[true; 3], LOOKUPS];
+    }
+
+    #[test]
+    fn bulk() {
+        let mut bytes = [b'a'; 40];
+        assert_eq![find_non_ascii(&bytes), None];
+        for i in 0..bytes.len() {
+            bytes[i] = 0x80;
+            assert_eq![find_non_ascii(&bytes), Some(i)];
+            assert_eq![find_non_ascii(&bytes[i + 1..]), None];
+            bytes[i] = b'a';
+        }
+        assert![is_all_ascii(&[])];
+        assert![is_all_ascii(&bytes)];
+        assert![!is_all_ascii(&[b'a', 0xFF])];
+    }
+}
@@ -0,0 +1,395 @@
+// textos::ascii
+//
+//! ASCII related functionality.
+//
+
+use crate::unicode::char::{Char16, Char24, Char32, Char7, Char8};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::cmp::Ordering;
+
+mod class;
+mod string;
+pub use {class::*, string::*};
+
+/// Builds an if-else tree that returns a result depending on the uncased
+/// comparison with the given ASCII.
+///
+/// It expects a comparison value
+///
+/// It uses the `eq_ignore_ascii_case` function, so the comparsion value could
+/// be a [`&str`], [`char`], [`&[u8]`][slice] or [`u8`].
+///
+/// # Example
+/// ```
+/// use textos::ascii_eq_uncased;
+///
+/// fn ascii_eq(s: &str) -> Result<usize, &'static str> {
+///     ascii_eq_uncased![s, "No matches.", "Zero", 0, "One", 1, "Two", 2]
+/// }
+///
+/// assert_eq![Ok(0), ascii_eq("zErO")];
+/// assert_eq![Ok(1), ascii_eq("onE")];
+/// assert![ascii_eq("Three").is_err()];
+///
+/// // a single pair is also accepted
+/// let yes = "YES";
+/// assert_eq![Ok(true), ascii_eq_uncased![yes, (), "yes", true]];
+/// ```
+///
+/// For large sets of keywords see [`ascii_match_uncased!`].
+///
+/// [`&str`]: <https://doc.rust-lang.org/std/primitive.str.html#method.eq_ignore_ascii_case>
+/// [`char`]: <https://doc.rust-lang.org/std/primitive.char.html#method.eq_ignore_ascii_case>
+/// [slice]: <https://doc.rust-lang.org/std/primitive.slice.html#method.eq_ignore_ascii_case>
+/// [`u8`]: <https://doc.rust-lang.org/std/primitive.u8.html#method.eq_ignore_ascii_case>
+///
+#[macro_export]
+macro_rules! ascii_eq_uncased {
+    (
+        // The ASCII value to match against.
+        $value:ident,
+
+        // The error returned when there are no matches.
+        $error:expr,
+
+        // The first string and result matching pair.
+        $str:literal, $res:expr
+
+        // Optional additional string and result matching pairs.
+        $(, $ostr:literal, $ores:expr)* $(,)?
+
+    ) => {
+        if $value.eq_ignore_ascii_case(&$str) {
+            Ok($res)
+        }
+        $(
+            else if $value.eq_ignore_ascii_case(&$ostr) { Ok($ores) }
+        )*
+        else {
+            Err($error)
+        }
+    }
+}
+
+/// Matches a value against a set of ASCII keywords, ignoring the case,
+/// and returns an [`AsciiMatch`] with the index, length and result of the
+/// matching keyword.
+///
+/// The keywords are sorted at compile time into an [`AsciiKeywords`] table,
+/// by length and then by bytes, so each match is a binary search instead
+/// of a comparison with every keyword. Only the result of the matching
+/// keyword is evaluated.
+///
+/// The value can be any [`AsciiKey`], like [`&str`][str], `&[u8]`, [`u8`],
+/// [`char`] or [`Char7`].
+///
+/// With `prefix:` before the value, it matches the longest keyword
+/// that is a prefix of the value.
+///
+/// # Examples
+/// ```
+/// use textos::{ascii_match_uncased, all::Char7};
+///
+/// let m = ascii_match_uncased!["WHILE", "if" => 1, "else" => 2, "while" => 3].unwrap();
+/// assert_eq![(m.index, m.len, m.value), (2, 5, 3)];
+/// assert![ascii_match_uncased![b"whilst".as_slice(), "if" => 1, "while" => 3].is_none()];
+/// assert_eq![ascii_match_uncased![Char7::try_from('X').unwrap(), "x" => 'x'].unwrap().value, 'x'];
+///
+/// let m = ascii_match_uncased![prefix: "Content-Length: 42", "content-type" => 0, "content-length" => 1];
+/// assert_eq![m.map(|m| m.len), Some(14)];
+/// ```
+///
+/// Repeated keywords fail to compile:
+/// ```compile_fail
+/// # use textos::ascii_match_uncased;
+/// ascii_match_uncased!["a", "abc" => 1, "ABC" => 2];
+/// ```
+#[macro_export]
+macro_rules! ascii_match_uncased {
+    (prefix: $value:expr, $($key:literal => $res:expr),+ $(,)?) => {
+        $crate::ascii_match_uncased![@find_prefix, $value, $($key => $res),+]
+    };
+    ($value:expr, $($key:literal => $res:expr),+ $(,)?) => {
+        $crate::ascii_match_uncased![@find, $value, $($key => $res),+]
+    };
+    (@$find:ident, $value:expr, $($key:literal => $res:expr),+) => {{
+        const KEYWORDS: $crate::all::AsciiKeywords<'static, { [$($key),+].len() }> =
+            $crate::all::AsciiKeywords::new([$($key),+]);
+        let mut buffer = [0_u8; 4];
+        // the temporaries of the scrutinee live until the end of the match
+        match KEYWORDS.$find($crate::all::AsciiKey::ascii_key(&$value, &mut buffer)) {
+            Some((index, len)) => 'found: {
+                // only the result of the matching keyword is evaluated
+                let mut i = 0_usize;
+                $(
+                    i += 1;
+                    if index + 1 == i {
+                        break 'found Some($crate::all::AsciiMatch { index, len, value: $res });
+                    }
+                )+
+                None
+            }
+            None => None,
+        }
+    }};
+}
+
+/// A match returned by [`ascii_match_uncased!`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AsciiMatch<T> {
+    /// The index of the matching keyword, in the order they were given.
+    pub index: usize,
+    /// The length of the matching keyword, in bytes.
+    pub len: usize,
+    /// The result of the matching keyword.
+    pub value: T,
+}
+
+/// A table of ASCII keywords sorted for matching them ignoring the case.
+///
+/// The keywords are sorted by length and then by bytes in lower case, and
+/// they are searched with a binary search.
+///
+/// # Examples
+/// ```
+/// use textos::all::AsciiKeywords;
+///
+/// const KEYWORDS: AsciiKeywords<4> = AsciiKeywords::new(["let", "fn", "loop", "for"]);
+///
+/// assert_eq![KEYWORDS.find(b"FOR"), Some((3, 3))];
+/// assert_eq![KEYWORDS.find(b"lets"), None];
+/// assert_eq![KEYWORDS.find_prefix(b"Loops"), Some((2, 4))];
+/// assert_eq![KEYWORDS.keyword(1), Some("fn")];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AsciiKeywords<'a, const N: usize> {
+    keywords: [&'a str; N],
+    /// The indices of the keywords, in sorted order.
+    order: [usize; N],
+}
+
+impl<'a, const N: usize> AsciiKeywords<'a, N> {
+    /// Returns a new table with the given `keywords`.
+    ///
+    /// # Panics
+    /// Panics if a keyword is repeated, ignoring the ASCII case,
+    /// which fails the compilation in a constant context.
+    pub const fn new(keywords: [&'a str; N]) -> Self {
+        let mut order = [0; N];
+        let mut i = 0;
+        while i < N {
+            order[i] = i;
+            i += 1;
+        }
+        // insertion sort, since it runs at compile time
+        let mut i = 1;
+        while i < N {
+            let mut j = i;
+            while j > 0 {
+                let (a, b) = (
+                    keywords[order[j - 1]].as_bytes(),
+                    keywords[order[j]].as_bytes(),
+                );
+                match cmp_uncased(a, b) {
+                    Ordering::Greater => {
+                        let swap = order[j - 1];
+                        order[j - 1] = order[j];
+                        order[j] = swap;
+                    }
+                    Ordering::Equal => panic!["repeated ASCII keyword"],
+                    Ordering::Less => break,
+                }
+                j -= 1;
+            }
+            i += 1;
+        }
+        Self { keywords, order }
+    }
+
+    /// Returns the keyword at `index`, in the order they were given.
+    #[inline]
+    pub const fn keyword(&self, index: usize) -> Option<&'a str> {
+        if index < N {
+            Some(self.keywords[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index and length of the keyword equal to `bytes`,
+    /// ignoring the ASCII case.
+    pub const fn find(&self, bytes: &[u8]) -> Option<(usize, usize)> {
+        let (mut low, mut high) = (0, N);
+        while low < high {
+            let mid = (low + high) / 2;
+            let index = self.order[mid];
+            match cmp_uncased(self.keywords[index].as_bytes(), bytes) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Some((index, bytes.len())),
+            }
+        }
+        None
+    }
+
+    /// Returns the index and length of the longest keyword that is a prefix
+    /// of `bytes`, ignoring the ASCII case.
+    pub const fn find_prefix(&self, bytes: &[u8]) -> Option<(usize, usize)> {
+        // the longest keyword is the last one
+        let longest = if N > 0 {
+            self.keywords[self.order[N - 1]].len()
+        } else {
+            0
+        };
+        let mut len = if bytes.len() < longest {
+            bytes.len()
+        } else {
+            longest
+        };
+        loop {
+            if let Some(found) = self.find(bytes.split_at(len).0) {
+                return Some(found);
+            }
+            if len == 0 {
+                return None;
+            }
+            len -= 1;
+        }
+    }
+}
+
+/// Compares two byte strings by length, and then by bytes in ASCII lower case.
+const fn cmp_uncased(a: &[u8], b: &[u8]) -> Ordering {
+    if a.len() != b.len() {
+        return if a.len() < b.len() {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+    }
+    let mut i = 0;
+    while i < a.len() {
+        let (x, y) = (a[i].to_ascii_lowercase(), b[i].to_ascii_lowercase());
+        if x != y {
+            return if x < y {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        i += 1;
+    }
+    Ordering::Equal
+}
+
+/// A value that can be matched as ASCII bytes by [`ascii_match_uncased!`].
+///
+/// The scalars are encoded as UTF-8 into the given `buffer`.
+pub trait AsciiKey {
+    /// Returns the bytes of the value.
+    fn ascii_key<'a>(&'a self, buffer: &'a mut [u8; 4]) -> &'a [u8];
+}
+
+impl<T: AsciiKey + ?Sized> AsciiKey for &T {
+    #[inline]
+    fn ascii_key<'a>(&'a self, buffer: &'a mut [u8; 4]) -> &'a [u8] {
+        (**self).ascii_key(buffer)
+    }
+}
+impl AsciiKey for str {
+    #[inline]
+    fn ascii_key<'a>(&'a self, _: &'a mut [u8; 4]) -> &'a [u8] {
+        self.as_bytes()
+    }
+}
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl AsciiKey for String {
+    #[inline]
+    fn ascii_key<'a>(&'a self, _: &'a mut [u8; 4]) -> &'a [u8] {
+        self.as_bytes()
+    }
+}
+impl AsciiKey for [u8] {
+    #[inline]
+    fn ascii_key<'a>(&'a self, _: &'a mut [u8; 4]) -> &'a [u8] {
+        self
+    }
+}
+impl<const N: usize> AsciiKey for [u8; N] {
+    #[inline]
+    fn ascii_key<'a>(&'a self, _: &'a mut [u8; 4]) -> &'a [u8] {
+        self
+    }
+}
+impl AsciiKey for u8 {
+    #[inline]
+    fn ascii_key<'a>(&'a self, _: &'a mut [u8; 4]) -> &'a [u8] {
+        core::slice::from_ref(self)
+    }
+}
+impl AsciiKey for char {
+    #[inline]
+    fn ascii_key<'a>(&'a self, buffer: &'a mut [u8; 4]) -> &'a [u8] {
+        self.encode_utf8(buffer).as_bytes()
+    }
+}
+macro_rules! impl_ascii_key {
+    ($($t:ty),+) => { $(
+        impl AsciiKey for $t {
+            #[inline]
+            fn ascii_key<'a>(&'a self, buffer: &'a mut [u8; 4]) -> &'a [u8] {
+                self.to_char().encode_utf8(buffer).as_bytes()
+            }
+        }
+    )+ };
+}
+impl_ascii_key![Char7, Char8, Char16, Char24, Char32];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        const EMPTY: AsciiKeywords<0> = AsciiKeywords::new([]);
+        assert_eq![EMPTY.find(b""), None];
+        assert_eq![EMPTY.find_prefix(b"a"), None];
+
+        const K: AsciiKeywords<5> = AsciiKeywords::new(["b", "", "ab", "Aa", "abc"]);
+        for (i, k) in ["b", "", "ab", "aa", "ABC"].iter().enumerate() {
+            assert_eq![K.find(k.as_bytes()), Some((i, k.len()))];
+        }
+        assert_eq![K.find(b"c"), None];
+        assert_eq![K.find_prefix(b"abd"), Some((2, 2))];
+        assert_eq![K.find_prefix(b"x"), Some((1, 0))];
+    }
+
+    #[test]
+    fn matches() {
+        let m = |s: &str| ascii_match_uncased![prefix: s, "a" => 'a', "ab" => 'b', "b" => 'c'];
+        assert_eq![
+            m("ABZ"),
+            Some(AsciiMatch {
+                index: 1,
+                len: 2,
+                value: 'b'
+            })
+        ];
+        assert_eq![
+            m("a"),
+            Some(AsciiMatch {
+                index: 0,
+                len: 1,
+                value: 'a'
+            })
+        ];
+        assert_eq![m("z"), None];
+        assert_eq![
+            ascii_match_uncased![b'B', "a" => 0, "b" => 1].map(|m| m.value),
+            Some(1)
+        ];
+        assert_eq![ascii_match_uncased!['ñ', "n" => 0].map(|m| m.value), None];
+    }
+}
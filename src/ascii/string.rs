@@ -0,0 +1,832 @@
+// textos::ascii::string
+//
+//! ASCII strings.
+//
+// TOC
+// - StaticAsciiString
+// - AsciiString
+// - AsciiChars
+// - helper fns
+
+use crate::{
+    error::{TextosError as Error, TextosResult as Result},
+    macros::impl_cmp_str,
+    textual::Textual,
+    unicode::char::Char7,
+};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{fmt, iter::FusedIterator, ops::Deref};
+
+/* StaticAsciiString */
+
+/// An ASCII string, backed by an array, with 255 bytes of maximum constant capacity.
+///
+/// Its elements are [`Char7`]s, so it's guaranteed to be ASCII, each element
+/// is a single byte, and the case conversions preserve the length.
+///
+/// Comparisons and hashing are based on the string contents, so strings with
+/// different capacities can be compared between them, and with [`str`] slices.
+///
+/// # Examples
+/// ```
+/// use textos::{ascii::StaticAsciiString, unicode::char::Char7};
+///
+/// const GREETING: StaticAsciiString<16> = StaticAsciiString::from_literal(b"Hello!");
+///
+/// assert_eq![GREETING, "Hello!"];
+/// assert_eq![GREETING.get(1), Char7::try_from_u8(b'e').ok()];
+/// assert_eq![GREETING.to_ascii_uppercase(), "HELLO!"];
+/// assert![StaticAsciiString::<16>::from_bytes("¡Hola!".as_bytes()).is_err()];
+/// ```
+#[derive(Clone, Copy)]
+pub struct StaticAsciiString<const CAP: usize> {
+    // all the bytes up to len are ASCII
+    arr: [u8; CAP],
+    len: u8,
+}
+
+impl<const CAP: usize> StaticAsciiString<CAP> {
+    /// Creates a new empty `StaticAsciiString`.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub const fn new() -> Self {
+        assert![CAP <= 255];
+        Self {
+            arr: [0; CAP],
+            len: 0,
+        }
+    }
+
+    /// Creates a new `StaticAsciiString` from a slice of ASCII `bytes`.
+    ///
+    /// # Errors
+    /// Returns [`InvalidScalar`][Error::InvalidScalar] with the first character
+    /// that is not ASCII, [`Utf8`][Error::Utf8] if the bytes are not valid UTF-8,
+    /// or [`NotEnoughCapacity`][Error::NotEnoughCapacity] if they don't fit.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if let Err(e) = check_ascii(bytes) {
+            return Err(e);
+        }
+        if bytes.len() > CAP {
+            return Err(Error::NotEnoughCapacity(bytes.len()));
+        }
+        let mut new = Self::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            new.arr[i] = bytes[i];
+            i += 1;
+        }
+        new.len = bytes.len() as u8;
+        Ok(new)
+    }
+
+    /// Creates a new `StaticAsciiString` from a byte literal, in constant contexts.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255, if a byte is not ASCII, or if they don't fit,
+    /// which fails the compilation in a constant context.
+    pub const fn from_literal(bytes: &[u8]) -> Self {
+        assert![check_ascii(bytes).is_ok(), "the bytes must be ASCII"];
+        assert![bytes.len() <= CAP, "the bytes must fit in the capacity"];
+        let mut new = Self::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            new.arr[i] = bytes[i];
+            i += 1;
+        }
+        new.len = bytes.len() as u8;
+        new
+    }
+
+    /// Creates a new `StaticAsciiString` from a `Char7`.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255 or < 1.
+    #[inline]
+    pub const fn from_char7(c: Char7) -> Self {
+        let mut new = Self::new();
+        new.arr[0] = c.to_u8();
+        new.len = 1;
+        new
+    }
+
+    //
+
+    /// Returns the total capacity in bytes.
+    #[inline]
+    pub const fn capacity() -> usize {
+        CAP
+    }
+
+    /// Returns the remaining capacity.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP - self.len as usize
+    }
+
+    /// Returns the current length.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns `true` if the current length is 0.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the current remaining capacity is 0.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len as usize == CAP
+    }
+
+    /// Sets the length to 0.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    //
+
+    /// Returns the character at `index`, or `None` if it's out of bounds.
+    #[inline]
+    pub const fn get(&self, index: usize) -> Option<Char7> {
+        if index < self.len as usize {
+            Some(Char7::new_unchecked(self.arr[index]))
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the character at `index`.
+    ///
+    /// # Errors
+    /// Returns [`OutOfBounds`][Error::OutOfBounds] if `index` >= the length.
+    #[inline]
+    pub fn set(&mut self, index: usize, c: Char7) -> Result<()> {
+        if index < self.len as usize {
+            self.arr[index] = c.to_u8();
+            Ok(())
+        } else {
+            Err(Error::OutOfBounds)
+        }
+    }
+
+    /// Appends a character.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][Error::NotEnoughCapacity] if it's full.
+    #[inline]
+    pub fn push(&mut self, c: Char7) -> Result<()> {
+        if self.is_full() {
+            Err(Error::NotEnoughCapacity(CAP + 1))
+        } else {
+            self.arr[self.len as usize] = c.to_u8();
+            self.len += 1;
+            Ok(())
+        }
+    }
+
+    /// Removes the last character and returns it, or `None` if it's empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Char7> {
+        let c = self.get((self.len as usize).checked_sub(1)?);
+        self.len -= 1;
+        c
+    }
+
+    /// Appends the complete string slice.
+    ///
+    /// # Errors
+    /// Returns [`InvalidScalar`][Error::InvalidScalar] with the first character
+    /// that is not ASCII, or [`NotEnoughCapacity`][Error::NotEnoughCapacity]
+    /// if it doesn't fit, without appending anything.
+    pub fn push_str(&mut self, string: &str) -> Result<()> {
+        check_ascii_str(string)?;
+        let (start, end) = (self.len as usize, self.len as usize + string.len());
+        if end > CAP {
+            return Err(Error::NotEnoughCapacity(end));
+        }
+        self.arr[start..end].copy_from_slice(string.as_bytes());
+        self.len = end as u8;
+        Ok(())
+    }
+
+    /// Returns an iterator over the characters.
+    #[inline]
+    pub fn chars(&self) -> AsciiChars<'_> {
+        AsciiChars(self.as_bytes().iter())
+    }
+
+    //
+
+    /// Returns a copy with the ASCII letters in upper case.
+    #[inline]
+    #[must_use]
+    pub const fn to_ascii_uppercase(&self) -> Self {
+        let mut new = *self;
+        let mut i = 0;
+        while i < self.len as usize {
+            new.arr[i] = self.arr[i].to_ascii_uppercase();
+            i += 1;
+        }
+        new
+    }
+
+    /// Returns a copy with the ASCII letters in lower case.
+    #[inline]
+    #[must_use]
+    pub const fn to_ascii_lowercase(&self) -> Self {
+        let mut new = *self;
+        let mut i = 0;
+        while i < self.len as usize {
+            new.arr[i] = self.arr[i].to_ascii_lowercase();
+            i += 1;
+        }
+        new
+    }
+
+    /// Converts the ASCII letters to upper case in place.
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.arr[..self.len as usize].make_ascii_uppercase();
+    }
+
+    /// Converts the ASCII letters to lower case in place.
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.arr[..self.len as usize].make_ascii_lowercase();
+    }
+
+    //
+
+    /// Returns a byte slice of the inner string slice.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        #[cfg(feature = "unsafe")]
+        unsafe {
+            self.arr.get_unchecked(0..self.len as usize)
+        }
+
+        #[cfg(not(feature = "unsafe"))]
+        self.arr
+            .get(0..self.len as usize)
+            .expect("len must be <= arr.len()")
+    }
+
+    /// Returns the inner string slice.
+    ///
+    /// With the `unsafe` feature it's a zero-cost conversion,
+    /// since the bytes are always ASCII.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        #[cfg(feature = "unsafe")]
+        // SAFETY: ASCII is valid UTF-8
+        unsafe {
+            core::str::from_utf8_unchecked(self.as_bytes())
+        }
+        #[cfg(not(feature = "unsafe"))]
+        core::str::from_utf8(self.as_bytes()).expect("must be valid ASCII")
+    }
+}
+
+impl_cmp_str![StaticAsciiString];
+
+impl<const CAP: usize> Textual for StaticAsciiString<CAP> {}
+
+impl<const CAP: usize> Default for StaticAsciiString<CAP> {
+    /// Returns an empty string.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAP: usize> fmt::Display for StaticAsciiString<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for StaticAsciiString<CAP> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl<const CAP: usize> fmt::Write for StaticAsciiString<CAP> {
+    /// Appends the complete string slice, or fails if it's not ASCII
+    /// or it doesn't fit.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const CAP: usize> Deref for StaticAsciiString<CAP> {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> TryFrom<&str> for StaticAsciiString<CAP> {
+    type Error = Error;
+
+    /// Tries to create a new `StaticAsciiString` from the given string slice.
+    ///
+    /// # Errors
+    /// Returns [`InvalidScalar`][Error::InvalidScalar] with the first character
+    /// that is not ASCII, or [`NotEnoughCapacity`][Error::NotEnoughCapacity]
+    /// if it doesn't fit.
+    #[inline]
+    fn try_from(string: &str) -> Result<Self> {
+        check_ascii_str(string)?;
+        Self::from_bytes(string.as_bytes())
+    }
+}
+
+/* AsciiString */
+
+/// An allocated ASCII string.
+///
+/// Its elements are [`Char7`]s, so it's guaranteed to be ASCII, each element
+/// is a single byte, and the case conversions preserve the length.
+///
+/// # Examples
+/// ```
+/// use textos::{ascii::AsciiString, unicode::char::Char7};
+///
+/// let mut s = AsciiString::try_from("key").unwrap();
+/// s.push(Char7::try_from_u8(b'=').unwrap());
+/// s.push_str("Value").unwrap();
+/// assert_eq![s, "key=Value"];
+/// assert_eq![s.get(3), Char7::try_from_u8(b'=').ok()];
+/// assert![s.push_str("ñ").is_err()];
+///
+/// s.make_ascii_uppercase();
+/// assert_eq![s.into_string(), "KEY=VALUE"];
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiString {
+    // it's always ASCII
+    string: String,
+}
+
+#[cfg(feature = "alloc")]
+impl AsciiString {
+    /// Creates a new empty `AsciiString`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            string: String::new(),
+        }
+    }
+
+    /// Creates a new empty `AsciiString` with the given `capacity`.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            string: String::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a new `AsciiString` from a slice of ASCII `bytes`.
+    ///
+    /// # Errors
+    /// Returns [`InvalidScalar`][Error::InvalidScalar] with the first character
+    /// that is not ASCII, or [`Utf8`][Error::Utf8] if the bytes are not valid UTF-8.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_vec(bytes.to_vec())
+    }
+
+    /// Creates a new `AsciiString` from a vector of ASCII `bytes`, without copying.
+    ///
+    /// # Errors
+    /// Returns [`InvalidScalar`][Error::InvalidScalar] with the first character
+    /// that is not ASCII, or [`Utf8`][Error::Utf8] if the bytes are not valid UTF-8.
+    pub fn from_vec(bytes: Vec<u8>) -> Result<Self> {
+        check_ascii(&bytes)?;
+        Ok(Self {
+            string: String::from_utf8(bytes).map_err(|e| e.utf8_error())?,
+        })
+    }
+
+    /// Returns the current length.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    /// Returns `true` if the current length is 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.string.is_empty()
+    }
+
+    /// Sets the length to 0.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.string.clear();
+    }
+
+    //
+
+    /// Returns the character at `index`, or `None` if it's out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Char7> {
+        self.string
+            .as_bytes()
+            .get(index)
+            .map(|b| Char7::new_unchecked(*b))
+    }
+
+    /// Appends a character.
+    #[inline]
+    pub fn push(&mut self, c: Char7) {
+        self.string.push(c.to_char());
+    }
+
+    /// Removes the last character and returns it, or `None` if it's empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Char7> {
+        self.string.pop().map(|c| Char7::new_unchecked(c as u8))
+    }
+
+    /// Appends the complete string slice.
+    ///
+    /// # Errors
+    /// Returns [`InvalidScalar`][Error::InvalidScalar] with the first character
+    /// that is not ASCII, without appending anything.
+    pub fn push_str(&mut self, string: &str) -> Result<()> {
+        check_ascii_str(string)?;
+        self.string.push_str(string);
+        Ok(())
+    }
+
+    /// Returns an iterator over the characters.
+    #[inline]
+    pub fn chars(&self) -> AsciiChars<'_> {
+        AsciiChars(self.string.as_bytes().iter())
+    }
+
+    //
+
+    /// Returns a copy with the ASCII letters in upper case.
+    #[inline]
+    #[must_use]
+    pub fn to_ascii_uppercase(&self) -> Self {
+        Self {
+            string: self.string.to_ascii_uppercase(),
+        }
+    }
+
+    /// Returns a copy with the ASCII letters in lower case.
+    #[inline]
+    #[must_use]
+    pub fn to_ascii_lowercase(&self) -> Self {
+        Self {
+            string: self.string.to_ascii_lowercase(),
+        }
+    }
+
+    /// Converts the ASCII letters to upper case in place.
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.string.make_ascii_uppercase();
+    }
+
+    /// Converts the ASCII letters to lower case in place.
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.string.make_ascii_lowercase();
+    }
+
+    //
+
+    /// Returns a byte slice of the inner string slice.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+
+    /// Returns the inner string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Returns the inner string.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use super::*;
+    use core::borrow::Borrow;
+
+    impl Textual for AsciiString {}
+
+    impl fmt::Display for AsciiString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl fmt::Debug for AsciiString {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:?}", self.as_str())
+        }
+    }
+
+    impl fmt::Write for AsciiString {
+        /// Appends the complete string slice, or fails if it's not ASCII.
+        #[inline]
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.push_str(s).map_err(|_| fmt::Error)
+        }
+    }
+
+    impl Deref for AsciiString {
+        type Target = str;
+        #[inline]
+        fn deref(&self) -> &Self::Target {
+            self.as_str()
+        }
+    }
+
+    impl Borrow<str> for AsciiString {
+        #[inline]
+        fn borrow(&self) -> &str {
+            self.as_str()
+        }
+    }
+    impl AsRef<str> for AsciiString {
+        #[inline]
+        fn as_ref(&self) -> &str {
+            self.as_str()
+        }
+    }
+
+    impl PartialEq<str> for AsciiString {
+        #[inline]
+        fn eq(&self, other: &str) -> bool {
+            self.as_str() == other
+        }
+    }
+    impl PartialEq<&str> for AsciiString {
+        #[inline]
+        fn eq(&self, other: &&str) -> bool {
+            self.as_str() == *other
+        }
+    }
+    impl PartialEq<AsciiString> for str {
+        #[inline]
+        fn eq(&self, other: &AsciiString) -> bool {
+            self == other.as_str()
+        }
+    }
+    impl PartialEq<AsciiString> for &str {
+        #[inline]
+        fn eq(&self, other: &AsciiString) -> bool {
+            *self == other.as_str()
+        }
+    }
+
+    impl TryFrom<&str> for AsciiString {
+        type Error = Error;
+
+        /// Tries to create a new `AsciiString` from the given string slice.
+        ///
+        /// # Errors
+        /// Returns [`InvalidScalar`][Error::InvalidScalar] with the first
+        /// character that is not ASCII.
+        #[inline]
+        fn try_from(string: &str) -> Result<Self> {
+            check_ascii_str(string)?;
+            Ok(Self {
+                string: string.into(),
+            })
+        }
+    }
+    impl TryFrom<String> for AsciiString {
+        type Error = Error;
+
+        /// Tries to create a new `AsciiString` from the given string, without copying.
+        ///
+        /// # Errors
+        /// Returns [`InvalidScalar`][Error::InvalidScalar] with the first
+        /// character that is not ASCII.
+        #[inline]
+        fn try_from(string: String) -> Result<Self> {
+            check_ascii_str(&string)?;
+            Ok(Self { string })
+        }
+    }
+    impl<const CAP: usize> From<StaticAsciiString<CAP>> for AsciiString {
+        #[inline]
+        fn from(string: StaticAsciiString<CAP>) -> Self {
+            Self {
+                string: string.as_str().into(),
+            }
+        }
+    }
+    impl From<AsciiString> for String {
+        #[inline]
+        fn from(string: AsciiString) -> Self {
+            string.string
+        }
+    }
+}
+
+/* AsciiChars */
+
+/// An iterator over the [`Char7`]s of an ASCII string.
+///
+/// It's returned by [`StaticAsciiString::chars`] and `AsciiString::chars`.
+#[derive(Clone, Debug)]
+pub struct AsciiChars<'a>(core::slice::Iter<'a, u8>);
+
+impl Iterator for AsciiChars<'_> {
+    type Item = Char7;
+
+    #[inline]
+    fn next(&mut self) -> Option<Char7> {
+        self.0.next().map(|b| Char7::new_unchecked(*b))
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for AsciiChars<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Char7> {
+        self.0.next_back().map(|b| Char7::new_unchecked(*b))
+    }
+}
+
+impl ExactSizeIterator for AsciiChars<'_> {}
+impl FusedIterator for AsciiChars<'_> {}
+
+/* helper fns */
+
+/// Checks that all the `bytes` are ASCII.
+///
+/// Returns [`InvalidScalar`][Error::InvalidScalar] with the first character
+/// that isn't, or [`Utf8`][Error::Utf8] if it's not valid UTF-8.
+const fn check_ascii(bytes: &[u8]) -> Result<()> {
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii() {
+            return Err(non_ascii_error(bytes, i));
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Returns the error for the non-ASCII byte at `index`, preceded by ASCII bytes.
+///
+/// It's [`InvalidScalar`][Error::InvalidScalar] with the character encoded
+/// from there, or [`Utf8`][Error::Utf8] if it's not valid UTF-8.
+const fn non_ascii_error(bytes: &[u8], index: usize) -> Error {
+    let lead = bytes[index];
+    let width = if lead >= 0xF0 {
+        4
+    } else if lead >= 0xE0 {
+        3
+    } else {
+        2
+    };
+    let (_, rest) = bytes.split_at(index);
+    if rest.len() >= width {
+        let (encoded, _) = rest.split_at(width);
+        if core::str::from_utf8(encoded).is_ok() {
+            // the bits of the lead byte after its length prefix,
+            // followed by 6 bits from each continuation byte
+            let mut scalar = (lead & (0x7F >> width)) as u32;
+            let mut i = 1;
+            while i < width {
+                scalar = (scalar << 6) | (encoded[i] & 0x3F) as u32;
+                i += 1;
+            }
+            return Error::InvalidScalar(scalar);
+        }
+    }
+    match core::str::from_utf8(bytes) {
+        Err(e) => Error::Utf8(e),
+        Ok(_) => unreachable!(),
+    }
+}
+
+/// Checks that all the characters of the `string` are ASCII.
+///
+/// Returns [`InvalidScalar`][Error::InvalidScalar] with the first character that isn't.
+fn check_ascii_str(string: &str) -> Result<()> {
+    match string.chars().find(|c| !c.is_ascii()) {
+        Some(c) => Err(Error::InvalidScalar(c as u32)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_ascii() {
+        let mut s = StaticAsciiString::<4>::new();
+        s.push_str("ab").unwrap();
+        s.push(Char7::try_from_u8(b'C').unwrap()).unwrap();
+        assert![s.push_str("de").is_err()];
+        assert_eq![s.push_str("ñ"), Err(Error::InvalidScalar('ñ' as u32))];
+        assert_eq![s, "abC"];
+
+        s.set(0, Char7::try_from_u8(b'x').unwrap()).unwrap();
+        assert![s.set(3, Char7::NUL).is_err()];
+        assert_eq![s.to_ascii_lowercase(), "xbc"];
+        assert![s.chars().rev().map(Char7::to_u8).eq(*b"Cbx")];
+
+        assert_eq![s.pop(), Char7::try_from_u8(b'C').ok()];
+        assert_eq![s.len(), 2];
+        s.clear();
+        assert_eq![s.pop(), None];
+        assert![StaticAsciiString::<2>::from_bytes(b"abc").is_err()];
+        // the errors name the characters, not their first bytes
+        assert_eq![
+            StaticAsciiString::<8>::from_bytes("a€".as_bytes()),
+            Err(Error::InvalidScalar('€' as u32))
+        ];
+        assert_eq![
+            StaticAsciiString::<8>::from_bytes("ab🦀".as_bytes()),
+            Err(Error::InvalidScalar('🦀' as u32))
+        ];
+        let Err(Error::Utf8(e)) = StaticAsciiString::<8>::from_bytes(b"ab\xE2\x82") else {
+            panic!("an incomplete sequence is not valid UTF-8");
+        };
+        assert_eq![e.valid_up_to(), 2];
+        assert_eq![
+            StaticAsciiString::<4>::try_from("añ"),
+            Err(Error::InvalidScalar('ñ' as u32))
+        ];
+        assert_eq![
+            StaticAsciiString::<2>::try_from("abc"),
+            Err(Error::NotEnoughCapacity(3))
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn ascii_string() {
+        use alloc::{string::String, vec};
+
+        let mut s = AsciiString::from_vec(vec![b'a', b'B']).unwrap();
+        assert_eq![
+            AsciiString::from_vec(vec![b'a', 0xC3, 0xB1]),
+            Err(Error::InvalidScalar('ñ' as u32))
+        ];
+        assert_eq![s.pop(), Char7::try_from_u8(b'B').ok()];
+        assert_eq![s.pop(), Char7::try_from_u8(b'a').ok()];
+        assert_eq![s.pop(), None];
+
+        let s = AsciiString::try_from(String::from("xyz")).unwrap();
+        assert_eq![s, "xyz"];
+        assert_eq![
+            AsciiString::try_from(String::from("xñ")),
+            Err(Error::InvalidScalar('ñ' as u32))
+        ];
+
+        let small = StaticAsciiString::<4>::try_from("abc").unwrap();
+        assert_eq![AsciiString::from(small), "abc"];
+        assert_eq![String::from(AsciiString::from(small)), "abc"];
+    }
+
+    #[test]
+    fn control_chars() {
+        assert_eq![Char7::LF.to_char(), '\n'];
+        assert_eq![Char7::ESC.to_u8(), 0x1B];
+        assert_eq![Char7::DEL, Char7::MAX];
+        assert![Char7::try_from_u8(0x80).is_err()];
+    }
+}
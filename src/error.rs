@@ -3,7 +3,7 @@
 //! Error types.
 //
 
-use core::{result, str::Utf8Error};
+use core::{fmt, result, str::Utf8Error};
 // use sixbit::EncodeError;
 
 /// `textos` result type.
@@ -11,7 +11,7 @@ pub type TextosResult<N> = result::Result<N, TextosError>;
 
 /// `textos` error type.
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TextosError {
     // /// An error involving the encoding of a rate's name.
     // RateName(EncodeError),
@@ -31,18 +31,83 @@ pub enum TextosError {
     /// Returns the needed number of elements.
     NotEnoughElements(usize),
 
-    // ///
-    // PushCapacity(String),
+    /// The index is not on a `char` boundary.
+    ///
+    /// Returns the byte index.
+    NotCharBoundary {
+        /// The byte index.
+        index: usize,
+    },
+
+    /// The scalar value is not valid for the attempted operation.
+    ///
+    /// It may be a surrogate, out of the Unicode range, or not representable
+    /// by the target type, like a non-ASCII value for an ASCII type.
+    ///
+    /// Returns the invalid value.
+    InvalidScalar(u32),
+
+    /// A nul character where it's not allowed.
+    ///
+    /// Returns the byte position of the nul character.
+    NulCharacter {
+        /// The byte position of the error.
+        position: usize,
+    },
+
+    /// The text is not a single extended grapheme cluster.
+    NotSingleGrapheme,
+
+    /// The escape sequence is not valid.
+    ///
+    /// Returns the byte position of the start of the escape sequence.
+    InvalidEscape {
+        /// The byte position of the error.
+        position: usize,
+    },
+
+    /// The text is not a valid number.
+    ///
+    /// Returns the byte position of the first invalid character,
+    /// or the length of the text if it ended too soon.
+    InvalidNumber {
+        /// The byte position of the error.
+        position: usize,
+    },
+
+    /// The number doesn't fit in the target type.
+    ///
+    /// Returns the byte position of the digit that made it overflow.
+    NumberOverflow {
+        /// The byte position of the error.
+        position: usize,
+    },
+
+    /// The template is not valid.
+    ///
+    /// Returns the byte position of the syntax error.
+    InvalidTemplate {
+        /// The byte position of the error.
+        position: usize,
+    },
+
+    /// A template placeholder has no argument.
+    ///
+    /// Returns the byte position of the placeholder.
+    MissingArgument {
+        /// The byte position of the placeholder.
+        position: usize,
+    },
+
+    /// An error writing into a formatter.
+    Fmt(fmt::Error),
 
-    // ///
-    // NotCharBoundary,
     /// Errors which can occur when attempting to interpret a sequence of [`u8`]
     /// as a string.
     Utf8(Utf8Error),
 
-    /// A miscelaneous error.
-    #[cfg(feature = "std")]
-    Error(String),
+    /// A miscellaneous error, with its message.
+    Other(&'static str),
 }
 
 impl TextosError {
@@ -51,10 +116,30 @@ impl TextosError {
         matches![self, TextosError::Utf8(_)]
     }
 
-    // /// Returns `true` if the error is about bytes to UTF-8 conversion.
-    // pub const fn is_not_char_boundary(&self) -> bool {
-    //     matches![self, TextosError::Utf8(_)]
-    // }
+    /// Returns the byte position in the text where the error happened, if known.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::error::TextosError;
+    ///
+    /// assert_eq![TextosError::NotCharBoundary { index: 3 }.position(), Some(3)];
+    /// assert_eq![TextosError::InvalidEscape { position: 7 }.position(), Some(7)];
+    /// assert_eq![TextosError::NotSingleGrapheme.position(), None];
+    /// ```
+    pub const fn position(&self) -> Option<usize> {
+        use TextosError::*;
+        match self {
+            NotCharBoundary { index: p }
+            | NulCharacter { position: p }
+            | InvalidEscape { position: p }
+            | InvalidNumber { position: p }
+            | NumberOverflow { position: p }
+            | InvalidTemplate { position: p }
+            | MissingArgument { position: p } => Some(*p),
+            Utf8(e) => Some(e.valid_up_to()),
+            _ => None,
+        }
+    }
 }
 
 /// allows converting into `Error` from other error types.
@@ -68,6 +153,12 @@ mod core_impls {
         }
     }
 
+    impl From<fmt::Error> for TextosError {
+        fn from(e: fmt::Error) -> Self {
+            TextosError::Fmt(e)
+        }
+    }
+
     impl fmt::Display for TextosError {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             use TextosError::*;
@@ -75,10 +166,25 @@ mod core_impls {
                 OutOfBounds => write!(f, "The value is out of bounds."),
                 NotEnoughCapacity(c) => write!(f, "Not enough capacity. Needed: {c}"),
                 NotEnoughElements(e) => write!(f, "Not enough elements. Needed: {e}"),
-                Utf8(e) => fmt::Debug::fmt(e, f),
-
-                #[cfg(feature = "std")]
-                Error(s) => write!(f, "Error: {s}"),
+                NotCharBoundary { index } => write!(f, "Not a char boundary at byte {index}."),
+                InvalidScalar(s) => match char::from_u32(*s) {
+                    Some(c) => write!(f, "Invalid scalar U+{s:04X} {c:?}."),
+                    None => write!(f, "Invalid scalar U+{s:04X}."),
+                },
+                NulCharacter { position } => write!(f, "Nul character at byte {position}."),
+                NotSingleGrapheme => write!(f, "Not a single extended grapheme cluster."),
+                InvalidEscape { position } => write!(f, "Invalid escape at byte {position}."),
+                InvalidNumber { position } => write!(f, "Invalid number at byte {position}."),
+                NumberOverflow { position } => {
+                    write!(f, "The number overflows at byte {position}.")
+                }
+                InvalidTemplate { position } => write!(f, "Invalid template at byte {position}."),
+                MissingArgument { position } => {
+                    write!(f, "Missing template argument at byte {position}.")
+                }
+                Fmt(e) => fmt::Display::fmt(e, f),
+                Utf8(e) => fmt::Display::fmt(e, f),
+                Other(message) => write!(f, "{message}"),
             }
         }
     }
@@ -0,0 +1,306 @@
+// textos::fmt::align
+//
+//! Alignment.
+//
+
+use crate::{
+    error::TextosResult as Result,
+    unicode::{
+        string::StaticU8String,
+        width::{str_truncate_width, str_width},
+    },
+};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{fmt, str::SplitWhitespace};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The alignment of some content inside a wider space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// Aligned to the left.
+    #[default]
+    Left,
+
+    /// Centered, leaning to the left if the space can't be split evenly.
+    Center,
+
+    /// Aligned to the right.
+    Right,
+}
+
+impl Alignment {
+    /// Splits the given `padding` into the amounts to add
+    /// to the left and to the right of the content.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::fmt::Alignment;
+    ///
+    /// assert_eq![(0, 5), Alignment::Left.split(5)];
+    /// assert_eq![(2, 3), Alignment::Center.split(5)];
+    /// assert_eq![(5, 0), Alignment::Right.split(5)];
+    /// ```
+    #[inline]
+    pub const fn split(self, padding: usize) -> (usize, usize) {
+        match self {
+            Alignment::Left => (0, padding),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+            Alignment::Right => (padding, 0),
+        }
+    }
+}
+
+/// # Aligning text
+impl Alignment {
+    /// Writes each line of the `text` aligned in `width` columns,
+    /// padded with spaces.
+    ///
+    /// The widths are measured by extended grapheme clusters, and the lines
+    /// wider than `width` are truncated without splitting them.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::fmt::Alignment;
+    ///
+    /// let mut s = String::new();
+    /// Alignment::Center.write_aligned("漢字\ne\u{301}", 5, &mut s).unwrap();
+    /// assert_eq![s, "漢字 \n  e\u{301}  "];
+    /// ```
+    pub fn write_aligned<W: fmt::Write>(
+        self,
+        text: &str,
+        width: usize,
+        writer: &mut W,
+    ) -> fmt::Result {
+        let mut lines = text.lines();
+        // the empty text is aligned as an empty line
+        let first = lines.next().unwrap_or("");
+        for (i, line) in core::iter::once(first).chain(lines).enumerate() {
+            if i > 0 {
+                writer.write_char('\n')?;
+            }
+            let (line, line_width) = str_truncate_width(line, width);
+            let (left, right) = self.split(width - line_width);
+            (0..left).try_for_each(|_| writer.write_char(' '))?;
+            writer.write_str(line)?;
+            (0..right).try_for_each(|_| writer.write_char(' '))?;
+        }
+        Ok(())
+    }
+
+    /// Returns each line of the `text` aligned in `width` columns.
+    ///
+    /// See [`write_aligned`][Self#method.write_aligned].
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::fmt::Alignment;
+    ///
+    /// assert_eq![Alignment::Right.align("ab\nc", 3), " ab\n  c"];
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    pub fn align(self, text: &str, width: usize) -> String {
+        let mut string = String::new();
+        // writing into a String can't fail
+        let _ = self.write_aligned(text, width, &mut string);
+        string
+    }
+
+    /// Returns each line of the `text` aligned in `width` columns,
+    /// in a static string.
+    ///
+    /// See [`write_aligned`][Self#method.write_aligned].
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][crate::error::TextosError::NotEnoughCapacity]
+    /// with the needed capacity if the result doesn't fit in `CAP` bytes.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::fmt::Alignment;
+    ///
+    /// assert_eq![Alignment::Left.align_static::<8>("ñ", 3).unwrap(), "ñ  "];
+    /// assert![Alignment::Left.align_static::<8>("ñ", 8).is_err()];
+    /// ```
+    pub fn align_static<const CAP: usize>(
+        self,
+        text: &str,
+        width: usize,
+    ) -> Result<StaticU8String<CAP>> {
+        StaticU8String::from_render(|mut w| self.write_aligned(text, width, &mut w))
+    }
+}
+
+/// Writes the `text` as a paragraph fully justified to `width` columns.
+///
+/// The words are separated by any whitespace, including line breaks,
+/// and the lines are filled greedily. The spaces between the words of each
+/// line are distributed to reach the `width`, giving the extra ones to the
+/// leftmost gaps. The last line, and the lines with a single word, are left
+/// aligned without padding.
+///
+/// The widths are measured by extended grapheme clusters, and the words wider
+/// than `width` are split between them.
+///
+/// # Examples
+/// ```
+/// use textos::fmt::write_justified;
+///
+/// let mut s = String::new();
+/// write_justified("the quick brown fox jumps over the lazy dog", 16, &mut s).unwrap();
+/// assert_eq![s, "\
+/// the  quick brown
+/// fox  jumps  over
+/// the lazy dog"];
+/// ```
+pub fn write_justified<W: fmt::Write>(text: &str, width: usize, writer: &mut W) -> fmt::Result {
+    let width = width.max(1);
+    let mut words = Words::new(text, width);
+    let mut first_line = true;
+    loop {
+        // measure the words that fit in the next line
+        let mut probe = words.clone();
+        let Some(first) = probe.next() else {
+            break;
+        };
+        let (mut count, mut used) = (1, str_width(first));
+        loop {
+            let mut ahead = probe.clone();
+            match ahead.next() {
+                Some(word) if used + count + str_width(word) <= width => {
+                    used += str_width(word);
+                    count += 1;
+                    probe = ahead;
+                }
+                _ => break,
+            }
+        }
+        let last_line = probe.clone().next().is_none();
+
+        if !first_line {
+            writer.write_char('\n')?;
+        }
+        first_line = false;
+        let gaps = count - 1;
+        let extra = if last_line || gaps == 0 {
+            0
+        } else {
+            width - used - gaps
+        };
+        for i in 0..count {
+            writer.write_str(words.next().unwrap_or(""))?;
+            if i < gaps {
+                let spaces = 1 + extra / gaps + usize::from(i < extra % gaps);
+                (0..spaces).try_for_each(|_| writer.write_char(' '))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the `text` as a paragraph fully justified to `width` columns.
+///
+/// See [`write_justified`].
+///
+/// # Examples
+/// ```
+/// use textos::fmt::justify;
+///
+/// assert_eq![justify("a bb  c\ndd", 6), "a bb c\ndd"];
+/// assert_eq![justify("a bb c dd", 7), "a  bb c\ndd"];
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub fn justify(text: &str, width: usize) -> String {
+    let mut string = String::new();
+    // writing into a String can't fail
+    let _ = write_justified(text, width, &mut string);
+    string
+}
+
+/// Returns the `text` as a paragraph fully justified to `width` columns,
+/// in a static string.
+///
+/// See [`write_justified`].
+///
+/// # Errors
+/// Returns [`NotEnoughCapacity`][crate::error::TextosError::NotEnoughCapacity]
+/// with the needed capacity if the result doesn't fit in `CAP` bytes.
+///
+/// # Panics
+/// Panics if `CAP` > 255.
+pub fn justify_static<const CAP: usize>(text: &str, width: usize) -> Result<StaticU8String<CAP>> {
+    StaticU8String::from_render(|mut w| write_justified(text, width, &mut w))
+}
+
+/// An iterator over the words of a text, where the words wider than `width`
+/// are split between extended grapheme clusters.
+#[derive(Clone)]
+struct Words<'a> {
+    words: SplitWhitespace<'a>,
+    rest: &'a str,
+    width: usize,
+}
+
+impl<'a> Words<'a> {
+    fn new(text: &'a str, width: usize) -> Self {
+        Self {
+            words: text.split_whitespace(),
+            rest: "",
+            width,
+        }
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            let word = self.words.next()?;
+            if str_width(word) <= self.width {
+                return Some(word);
+            }
+            self.rest = word;
+        }
+        let (piece, _) = str_truncate_width(self.rest, self.width);
+        // a grapheme wider than the line goes alone
+        let piece = if piece.is_empty() {
+            self.rest.graphemes(true).next().unwrap_or(self.rest)
+        } else {
+            piece
+        };
+        self.rest = &self.rest[piece.len()..];
+        Some(piece)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn justify_graphemes() {
+        assert_eq![
+            justify("e\u{301}e\u{301} 漢字 x", 8),
+            "e\u{301}e\u{301}  漢字\nx"
+        ];
+        assert_eq![justify("abcdefg hi", 3), "abc\ndef\ng\nhi"];
+        assert_eq![justify("漢字", 1), "漢\n字"];
+        assert_eq![justify("  ", 4), ""];
+        assert![justify_static::<4>("abc de", 3).is_err()];
+        assert_eq![justify_static::<8>("abc de", 3).unwrap(), "abc\nde"];
+    }
+
+    #[test]
+    fn align_lines() {
+        assert_eq![Alignment::Center.align("", 2), "  "];
+        assert_eq![Alignment::Left.align("abcd\r\nb", 3), "abc\nb  "];
+    }
+}
@@ -0,0 +1,255 @@
+// textos::fmt::case
+//
+//! Case styles.
+//
+
+use crate::{
+    error::TextosResult as Result,
+    unicode::{char::UnicodeScalar, string::StaticU8String},
+};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{fmt, iter::FusedIterator};
+
+/// A case style for identifiers and titles.
+///
+/// # Examples
+/// ```
+/// use textos::fmt::Case;
+///
+/// let mut s = String::new();
+/// Case::Snake.write("parseHTTPResponse2Json", &mut s).unwrap();
+/// assert_eq![s, "parse_http_response2_json"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `snake_case`.
+    Snake,
+    /// `camelCase`.
+    Camel,
+    /// `PascalCase`.
+    Pascal,
+    /// `kebab-case`.
+    Kebab,
+    /// `SCREAMING_SNAKE_CASE`.
+    ScreamingSnake,
+    /// `Title Case`.
+    Title,
+    /// `Train-Case`.
+    Train,
+}
+
+/// The case of the letters of a word.
+#[derive(Clone, Copy)]
+enum WordCase {
+    Lower,
+    Upper,
+    Capitalized,
+}
+
+impl Case {
+    /// All the case styles.
+    pub const ALL: [Self; 7] = [
+        Self::Snake,
+        Self::Camel,
+        Self::Pascal,
+        Self::Kebab,
+        Self::ScreamingSnake,
+        Self::Title,
+        Self::Train,
+    ];
+
+    /// Returns an iterator over the words of the `text`.
+    ///
+    /// The words are separated by any character that is not alphanumeric,
+    /// and by the changes of case:
+    /// - before an uppercase letter that follows a lowercase letter or a digit.
+    /// - before the last uppercase letter of an acronym followed by a lowercase one.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::fmt::Case;
+    ///
+    /// let words: Vec<_> = Case::words("XMLHttpRequest_v2 Base64Encode").collect();
+    /// assert_eq![words, ["XML", "Http", "Request", "v2", "Base64", "Encode"]];
+    /// ```
+    #[inline]
+    pub fn words(text: &str) -> CaseWords<'_> {
+        CaseWords { rest: text }
+    }
+
+    /// Returns the separator between words.
+    #[inline]
+    pub const fn separator(self) -> Option<char> {
+        match self {
+            Case::Snake | Case::ScreamingSnake => Some('_'),
+            Case::Kebab | Case::Train => Some('-'),
+            Case::Title => Some(' '),
+            Case::Camel | Case::Pascal => None,
+        }
+    }
+
+    /// Returns the case of the word at `index`.
+    const fn word_case(self, index: usize) -> WordCase {
+        match self {
+            Case::Snake | Case::Kebab => WordCase::Lower,
+            Case::ScreamingSnake => WordCase::Upper,
+            Case::Camel if index == 0 => WordCase::Lower,
+            Case::Camel | Case::Pascal | Case::Title | Case::Train => WordCase::Capitalized,
+        }
+    }
+
+    //
+
+    /// Writes the words of the `text` in this case style into the `writer`.
+    ///
+    /// See [`words`][Self#method.words] for how the words are detected.
+    pub fn write<W: fmt::Write>(self, text: &str, writer: &mut W) -> fmt::Result {
+        for (index, word) in Self::words(text).enumerate() {
+            if index > 0 {
+                if let Some(separator) = self.separator() {
+                    writer.write_char(separator)?;
+                }
+            }
+            for (i, c) in word.chars().enumerate() {
+                match self.word_case(index) {
+                    WordCase::Upper => write_upper(c, writer)?,
+                    WordCase::Capitalized if i == 0 => write_upper(c, writer)?,
+                    WordCase::Lower | WordCase::Capitalized => write_lower(c, writer)?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the words of the `text` in this case style.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::fmt::Case;
+    ///
+    /// assert_eq![Case::Camel.convert("HTTP server-error"), "httpServerError"];
+    /// assert_eq![Case::Pascal.convert("ñandú_grande"), "ÑandúGrande"];
+    /// assert_eq![Case::Kebab.convert("TitleCase"), "title-case"];
+    /// assert_eq![Case::ScreamingSnake.convert("maxValue"), "MAX_VALUE"];
+    /// assert_eq![Case::Title.convert("the_lord_of_rings"), "The Lord Of Rings"];
+    /// assert_eq![Case::Train.convert("content_type"), "Content-Type"];
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    pub fn convert(self, text: &str) -> String {
+        let mut string = String::with_capacity(text.len());
+        // writing into a String can't fail
+        let _ = self.write(text, &mut string);
+        string
+    }
+
+    /// Returns the words of the `text` in this case style, in a static string.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][crate::error::TextosError::NotEnoughCapacity]
+    /// with the needed capacity if the result doesn't fit in `CAP` bytes.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::fmt::Case;
+    ///
+    /// assert_eq![Case::Snake.convert_static::<16>("fooBar").unwrap(), "foo_bar"];
+    /// assert![Case::Snake.convert_static::<6>("fooBar").is_err()];
+    /// ```
+    pub fn convert_static<const CAP: usize>(self, text: &str) -> Result<StaticU8String<CAP>> {
+        StaticU8String::from_render(|mut w| self.write(text, &mut w))
+    }
+}
+
+/// Writes the uppercase mapping of `c`.
+fn write_upper<W: fmt::Write>(c: char, writer: &mut W) -> fmt::Result {
+    c.to_uppercase().try_for_each(|c| writer.write_char(c))
+}
+
+/// Writes the lowercase mapping of `c`.
+fn write_lower<W: fmt::Write>(c: char, writer: &mut W) -> fmt::Result {
+    c.to_lowercase().try_for_each(|c| writer.write_char(c))
+}
+
+/// An iterator over the words of an identifier or title.
+///
+/// It's returned by [`Case::words`].
+#[derive(Clone, Debug)]
+pub struct CaseWords<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for CaseWords<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let start = self.rest.find(UnicodeScalar::is_alphanumeric)?;
+        let text = &self.rest[start..];
+
+        let mut end = text.len();
+        let mut chars = text.char_indices().peekable();
+        let mut prev: Option<char> = None;
+        while let Some((i, c)) = chars.next() {
+            if !UnicodeScalar::is_alphanumeric(c) {
+                end = i;
+                break;
+            }
+            if let Some(p) = prev {
+                let upper = UnicodeScalar::is_uppercase(c);
+                // fooBar, base64Encode
+                let hump =
+                    upper && (UnicodeScalar::is_lowercase(p) || UnicodeScalar::is_numeric(p));
+                // HTTPServer
+                let acronym_end = upper
+                    && UnicodeScalar::is_uppercase(p)
+                    && chars
+                        .peek()
+                        .is_some_and(|(_, next)| UnicodeScalar::is_lowercase(*next));
+                if hump || acronym_end {
+                    end = i;
+                    break;
+                }
+            }
+            prev = Some(c);
+        }
+        self.rest = &text[end..];
+        Some(&text[..end])
+    }
+}
+
+impl FusedIterator for CaseWords<'_> {}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let idents = [
+            "snake_case",
+            "camelCase",
+            "PascalCase",
+            "kebab-case",
+            "SCREAMING_SNAKE",
+            "Title Case",
+            "Train-Case",
+        ];
+        for ident in idents {
+            for from in Case::ALL {
+                let converted = from.convert(ident);
+                for to in Case::ALL {
+                    assert_eq![to.convert(&converted), to.convert(ident)];
+                }
+            }
+        }
+        assert_eq![Case::Snake.convert("  __ "), ""];
+        assert_eq![Case::Snake.convert("ÁrbolÉpico"), "árbol_épico"];
+        assert_eq![Case::ScreamingSnake.convert("straße"), "STRASSE"];
+        assert_eq![Case::Camel.convert("IOError"), "ioError"];
+    }
+}
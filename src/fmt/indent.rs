@@ -1,9 +1,10 @@
 // textos::fmt::indent
-//!
+//
 //
 
 #[cfg(feature = "alloc")]
-use alloc::{format, string::String, vec::Vec};
+use alloc::string::String;
+use core::fmt;
 
 /// *`i`ndented `format`*.
 ///
@@ -24,25 +25,326 @@ macro_rules! iformat {
 #[cfg(feature = "alloc")]
 pub use iformat;
 
+/// *`i`ndented `write`*.
+///
+/// Writes the formatted arguments into a [`fmt::Write`]r, indented with an
+/// [`Indenter`], without allocating.
+///
+/// # Examples
+/// ```
+/// use textos::{fmt::{iwrite, Indenter}, unicode::string::StaticU8String};
+///
+/// let mut s = StaticU8String::<32>::new();
+/// iwrite!(s, Indenter::prefix("> "), "{}\n{}\n", "foo", "bar").unwrap();
+/// assert_eq!["> foo\n> bar\n", s];
+/// ```
+#[macro_export]
+macro_rules! iwrite {
+    ($writer:expr, $indenter:expr, $($args:tt)*) => {
+        ::core::fmt::Write::write_fmt(
+            &mut $crate::fmt::IndentWriter::new(&mut $writer, $indenter),
+            format_args![$($args)*],
+        )
+    };
+}
+pub use iwrite;
+
 /// Indents a multi-line `string` slice with the given number of `spaces`.
 ///
+/// The line endings are preserved.
+///
 /// # Examples
 /// ```
 /// use textos::fmt::indent;
 ///
 /// assert_eq!["  foo\n  bar", &indent(2, "foo\nbar")];
+/// assert_eq!["  foo\r\n  bar\n", &indent(2, "foo\r\nbar\n")];
 /// ```
 #[cfg(feature = "alloc")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
 pub fn indent(spaces: usize, string: &str) -> String {
-    let indentation = " ".repeat(spaces);
+    Indenter::spaces(spaces).indent(string)
+}
+
+/// The indentation of a line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Indentation<'a> {
+    /// A number of spaces.
+    Spaces(usize),
+    /// A number of tabs.
+    Tabs(usize),
+    /// A custom prefix, like `"> "` or `"// "`.
+    Prefix(&'a str),
+}
+
+impl Indentation<'_> {
+    /// Writes the indentation into the given `writer`.
+    pub fn render<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        match *self {
+            Indentation::Spaces(n) => (0..n).try_for_each(|_| writer.write_char(' ')),
+            Indentation::Tabs(n) => (0..n).try_for_each(|_| writer.write_char('\t')),
+            Indentation::Prefix(prefix) => writer.write_str(prefix),
+        }
+    }
+}
+
+impl Default for Indentation<'_> {
+    /// No indentation.
+    #[inline]
+    fn default() -> Self {
+        Self::Spaces(0)
+    }
+}
+
+/// A configurable indenter of multi-line text.
+///
+/// The first line and the rest of the lines (the hanging ones) can have
+/// different indentations, and the blank lines can be left unindented.
+///
+/// The line endings, including a trailing one, are preserved.
+///
+/// # Examples
+/// ```
+/// use textos::fmt::{Indentation, Indenter};
+///
+/// let list = Indenter::spaces(2)
+///     .with_first_line(Indentation::Prefix("- "))
+///     .with_skip_blank(true);
+/// let mut s = String::new();
+/// list.write("one\ntwo\n\nthree\n", &mut s).unwrap();
+/// assert_eq![s, "- one\n  two\n\n  three\n"];
+///
+/// s.clear();
+/// Indenter::tabs(1).write("a\r\nb", &mut s).unwrap();
+/// assert_eq![s, "\ta\r\n\tb"];
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Indenter<'a> {
+    first: Indentation<'a>,
+    hanging: Indentation<'a>,
+    skip_blank: bool,
+}
+
+impl<'a> Indenter<'a> {
+    /// Returns a new indenter with the same `indentation` for all the lines.
+    #[inline]
+    pub const fn new(indentation: Indentation<'a>) -> Self {
+        Self {
+            first: indentation,
+            hanging: indentation,
+            skip_blank: false,
+        }
+    }
+
+    /// Returns a new indenter of the given number of `spaces`.
+    #[inline]
+    pub const fn spaces(spaces: usize) -> Self {
+        Self::new(Indentation::Spaces(spaces))
+    }
+
+    /// Returns a new indenter of the given number of `tabs`.
+    #[inline]
+    pub const fn tabs(tabs: usize) -> Self {
+        Self::new(Indentation::Tabs(tabs))
+    }
+
+    /// Returns a new indenter with the given `prefix`.
+    #[inline]
+    pub const fn prefix(prefix: &'a str) -> Self {
+        Self::new(Indentation::Prefix(prefix))
+    }
+
+    /// Sets the indentation of the first line.
+    #[inline]
+    #[must_use]
+    pub const fn with_first_line(mut self, indentation: Indentation<'a>) -> Self {
+        self.first = indentation;
+        self
+    }
+
+    /// Sets the indentation of the lines after the first one.
+    #[inline]
+    #[must_use]
+    pub const fn with_hanging(mut self, indentation: Indentation<'a>) -> Self {
+        self.hanging = indentation;
+        self
+    }
 
-    let lines: Vec<&str> = string.lines().collect();
+    /// Sets whether to leave the blank lines unindented.
+    ///
+    /// The lines with only whitespace are blank too, and the first line
+    /// indentation goes to the first line that isn't skipped.
+    #[inline]
+    #[must_use]
+    pub const fn with_skip_blank(mut self, skip: bool) -> Self {
+        self.skip_blank = skip;
+        self
+    }
+
+    //
+
+    /// Writes the indented `text` into the given `writer`.
+    #[inline]
+    pub fn write<W: fmt::Write>(&self, text: &str, writer: &mut W) -> fmt::Result {
+        fmt::Write::write_str(&mut IndentWriter::new(writer, *self), text)
+    }
+
+    /// Returns the indented `text`.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    pub fn indent(&self, text: &str) -> String {
+        let mut string = String::with_capacity(text.len());
+        // writing into a String can't fail
+        let _ = self.write(text, &mut string);
+        string
+    }
+}
+
+/// A writer adapter that indents the text written through it,
+/// by using an [`Indenter`].
+///
+/// It keeps track of the line starts across writes, so the text
+/// can be written in pieces. A line is only considered blank when its
+/// line ending is written in the same piece, since it could continue otherwise.
+///
+/// # Examples
+/// ```
+/// use core::fmt::Write;
+/// use textos::fmt::{Indenter, IndentWriter};
+///
+/// let mut w = IndentWriter::new(String::new(), Indenter::prefix("// "));
+/// write!(w, "one").unwrap();
+/// write!(w, " two\nthree").unwrap();
+/// assert_eq![w.into_inner(), "// one two\n// three"];
+/// ```
+#[derive(Clone, Debug)]
+pub struct IndentWriter<'a, W: fmt::Write> {
+    writer: W,
+    indenter: Indenter<'a>,
+    line_start: bool,
+    first_line: bool,
+}
 
-    let mut indented_lines: Vec<String> = Vec::new();
-    for line in lines {
-        indented_lines.push(format!("{}{}", indentation, line));
+impl<'a, W: fmt::Write> IndentWriter<'a, W> {
+    /// Returns a new adapter over the given `writer`, starting at the first line.
+    #[inline]
+    pub const fn new(writer: W, indenter: Indenter<'a>) -> Self {
+        Self {
+            writer,
+            indenter,
+            line_start: true,
+            first_line: true,
+        }
     }
 
-    indented_lines.join("\n")
+    /// Returns the inner writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for IndentWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for segment in s.split_inclusive('\n') {
+            if self.line_start {
+                let blank = segment.ends_with('\n') && segment.trim().is_empty();
+                if !(blank && self.indenter.skip_blank) {
+                    if self.first_line {
+                        self.indenter.first.render(&mut self.writer)?;
+                    } else {
+                        self.indenter.hanging.render(&mut self.writer)?;
+                    }
+                    self.first_line = false;
+                }
+            }
+            self.writer.write_str(segment)?;
+            self.line_start = segment.ends_with('\n');
+        }
+        Ok(())
+    }
+}
+
+/// Removes the leading whitespace that all the non-blank lines have in common.
+///
+/// Tabs and spaces are not considered equal. The lines with only
+/// whitespace are emptied, and the line endings are preserved.
+///
+/// # Examples
+/// ```
+/// use textos::fmt::dedent;
+///
+/// assert_eq![dedent("    foo\n      bar\n  \n    baz\n"), "foo\n  bar\n\nbaz\n"];
+/// assert_eq![dedent("\tfoo\n  bar"), "\tfoo\n  bar"];
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub fn dedent(text: &str) -> String {
+    let mut string = String::with_capacity(text.len());
+    // writing into a String can't fail
+    let _ = write_dedented(text, &mut string);
+    string
+}
+
+/// Writes the `text` into the `writer`, without the leading whitespace
+/// that all the non-blank lines have in common.
+///
+/// See [`dedent`] for the details.
+pub fn write_dedented<W: fmt::Write>(text: &str, writer: &mut W) -> fmt::Result {
+    let margin = common_margin(text);
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            writer.write_str(&line[content.len()..])?;
+        } else {
+            writer.write_str(&line[margin.len()..])?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the leading whitespace that all the non-blank lines have in common.
+fn common_margin(text: &str) -> &str {
+    let mut margin: Option<&str> = None;
+    for line in text.lines().filter(|l| !l.trim().is_empty()) {
+        let leading = &line[..line.len() - line.trim_start().len()];
+        margin = Some(match margin {
+            None => leading,
+            Some(m) => {
+                let common = m
+                    .char_indices()
+                    .zip(leading.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map_or(m.len().min(leading.len()), |((i, _), _)| i);
+                &m[..common]
+            }
+        });
+    }
+    margin.unwrap_or("")
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_and_hanging() {
+        let hanging = Indenter::new(Indentation::Prefix("* "))
+            .with_hanging(Indentation::Tabs(1))
+            .with_skip_blank(true);
+        assert_eq![hanging.indent("\n\na\nb"), "\n\n* a\n\tb"];
+        assert_eq![hanging.indent(" \t\na\n  \nb"), " \t\n* a\n  \n\tb"];
+        assert_eq![hanging.indent("a\r\n\r\nb\r\n"), "* a\r\n\r\n\tb\r\n"];
+        assert_eq![hanging.indent(""), ""];
+        assert_eq![Indenter::prefix("#").indent("\n"), "#\n"];
+    }
+
+    #[test]
+    fn dedents() {
+        assert_eq![dedent(" \t a\n \t\n \t b"), "a\n\nb"];
+        assert_eq![dedent("  a\n b\n"), " a\nb\n"];
+        assert_eq![dedent("   \n"), "\n"];
+        assert_eq![dedent(&indent(3, "x\n y")), "x\n y"];
+    }
 }
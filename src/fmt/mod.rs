@@ -3,10 +3,17 @@
 //! Formatting.
 //
 
+mod align;
+mod case;
 mod indent;
+mod num;
+mod numeral;
+mod template;
 
+#[allow(unused_imports)] // for no_std
 pub use all::*;
 pub(super) mod all {
     #[doc(inline)]
-    pub use super::indent::*;
+    #[allow(unused_imports)] // for no_std
+    pub use super::{align::*, case::*, indent::*, num::*, numeral::*, template::*};
 }
@@ -0,0 +1,563 @@
+// textos::fmt::num
+//
+//! Number formatting.
+//!
+//! All the formatters are wrappers that implement [`Display`][fmt::Display],
+//! without allocating, so they can be written into any [`fmt::Write`]r,
+//! including a [`StaticU8String`].
+//!
+//! They honor the width, fill and alignment of the formatter, and are
+//! right-aligned by default, like the primitive numbers.
+//
+
+use crate::fmt::Alignment;
+#[cfg(doc)]
+use crate::unicode::string::StaticU8String;
+use core::{
+    fmt::{self, Write},
+    time::Duration,
+};
+
+/// A primitive integer.
+pub trait Integer: Copy + fmt::Display {
+    /// Returns the English ordinal suffix of the integer: `st`, `nd`, `rd` or `th`.
+    fn ordinal_suffix(self) -> &'static str;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),+) => { $(
+        impl Integer for $t {
+            #[allow(unused_comparisons)]
+            fn ordinal_suffix(self) -> &'static str {
+                // the remainder of negative numbers is negative
+                let rem = self % 100;
+                let rem = (if rem < 0 { 0 - rem } else { rem }) as u8;
+                match (rem % 10, rem / 10) {
+                    (_, 1) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                }
+            }
+        }
+    )+ };
+}
+impl_integer![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+/* grouped */
+
+/// A number with its integer digits grouped by a separator,
+/// and an optional fixed number of decimals.
+///
+/// The digits are grouped by 3, with a `,` separator and a `.` decimal point,
+/// by default.
+///
+/// # Examples
+/// ```
+/// use textos::{fmt::Grouped, unicode::string::StaticU8String};
+///
+/// assert_eq![Grouped::new(-1234567).to_string(), "-1,234,567"];
+/// assert_eq![Grouped::new(1234.5678).with_decimals(2).to_string(), "1,234.57"];
+///
+/// let indian = Grouped::new(12345678_u32).with_separator('_').with_group(4);
+/// assert_eq![StaticU8String::<16>::try_from_display(&indian).unwrap(), "1234_5678"];
+///
+/// let european = Grouped::new(9876.5).with_separator('.').with_decimal_point(',');
+/// assert_eq![european.to_string(), "9.876,5"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Grouped<T: fmt::Display> {
+    value: T,
+    separator: char,
+    group: usize,
+    decimal_point: char,
+    decimals: Option<usize>,
+}
+
+impl<T: fmt::Display> Grouped<T> {
+    /// Returns a new grouped number, with the default options.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            separator: ',',
+            group: 3,
+            decimal_point: '.',
+            decimals: None,
+        }
+    }
+
+    /// Sets the separator between groups.
+    #[inline]
+    #[must_use]
+    pub const fn with_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the number of digits of each group. 0 disables the grouping.
+    #[inline]
+    #[must_use]
+    pub const fn with_group(mut self, digits: usize) -> Self {
+        self.group = digits;
+        self
+    }
+
+    /// Sets the decimal point.
+    #[inline]
+    #[must_use]
+    pub const fn with_decimal_point(mut self, point: char) -> Self {
+        self.decimal_point = point;
+        self
+    }
+
+    /// Sets a fixed number of decimals, rounding the number.
+    ///
+    /// It only affects the floating-point numbers.
+    #[inline]
+    #[must_use]
+    pub const fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Writes the value, with the given number of decimals if any.
+    fn write_value<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        match self.decimals {
+            Some(decimals) => write!(writer, "{:.*}", decimals, self.value),
+            None => write!(writer, "{}", self.value),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grouped<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, |writer| {
+            // first count the integer digits, then write them grouped
+            let mut counter = GroupWriter::new(Counter(0), self, 0);
+            counter.group = 0;
+            self.write_value(&mut counter)?;
+            let digits = counter.seen;
+            self.write_value(&mut GroupWriter::new(writer, self, digits))
+        })
+    }
+}
+
+/// Writes into the formatter what the `write` function writes, padded to the
+/// width of the formatter with its fill and alignment, if it has a width.
+///
+/// The function is called twice when padding, for counting the chars first.
+fn pad<F>(f: &mut fmt::Formatter<'_>, write: F) -> fmt::Result
+where
+    F: Fn(&mut dyn fmt::Write) -> fmt::Result,
+{
+    let Some(width) = f.width() else {
+        return write(f);
+    };
+    let mut counter = Counter(0);
+    write(&mut counter)?;
+    let align = match f.align() {
+        Some(fmt::Alignment::Left) => Alignment::Left,
+        Some(fmt::Alignment::Center) => Alignment::Center,
+        Some(fmt::Alignment::Right) | None => Alignment::Right,
+    };
+    let (left, right) = align.split(width.saturating_sub(counter.0));
+    let fill = f.fill();
+    (0..left).try_for_each(|_| f.write_char(fill))?;
+    write(f)?;
+    (0..right).try_for_each(|_| f.write_char(fill))
+}
+
+/// A writer that discards everything, counting the written chars.
+struct Counter(usize);
+
+impl fmt::Write for Counter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// A writer adapter that inserts the separators between the integer digits
+/// of a formatted number, and replaces its decimal point.
+struct GroupWriter<W: fmt::Write> {
+    writer: W,
+    separator: char,
+    group: usize,
+    decimal_point: char,
+    /// The total number of integer digits.
+    digits: usize,
+    /// The number of integer digits seen so far.
+    seen: usize,
+    in_integer: bool,
+}
+
+impl<W: fmt::Write> GroupWriter<W> {
+    fn new<T: fmt::Display>(writer: W, grouped: &Grouped<T>, digits: usize) -> Self {
+        Self {
+            writer,
+            separator: grouped.separator,
+            group: grouped.group,
+            decimal_point: grouped.decimal_point,
+            digits,
+            seen: 0,
+            in_integer: true,
+        }
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for GroupWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.in_integer && c.is_ascii_digit() {
+                if self.seen > 0 && self.group > 0 && (self.digits - self.seen) % self.group == 0 {
+                    self.writer.write_char(self.separator)?;
+                }
+                self.seen += 1;
+                self.writer.write_char(c)?;
+            } else if self.in_integer && c == '.' {
+                self.in_integer = false;
+                self.writer.write_char(self.decimal_point)?;
+            } else {
+                // the integer part ends after its digits (e.g. before a unit)
+                self.in_integer &= self.seen == 0;
+                self.writer.write_char(c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* prefixed */
+
+/// The symbols of the SI prefixes, from 10⁰ to 10³⁰.
+const SI_PREFIXES: [&str; 11] = ["", "k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+
+/// The symbols of the IEC binary prefixes, from 2⁰ to 2⁸⁰.
+const IEC_PREFIXES: [&str; 9] = ["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"];
+
+/// A number scaled with the biggest [SI] or [IEC] prefix that keeps it
+/// greater or equal than 1, followed by an optional unit.
+///
+/// It has at most 1 decimal by default, and trailing zeros are removed.
+/// The numbers less than 1 don't get a prefix.
+///
+/// [SI]: https://en.wikipedia.org/wiki/Metric_prefix
+/// [IEC]: https://en.wikipedia.org/wiki/Binary_prefix
+///
+/// # Examples
+/// ```
+/// use textos::fmt::Prefixed;
+///
+/// assert_eq![Prefixed::iec(1536.0).with_unit("B").to_string(), "1.5 KiB"];
+/// assert_eq![Prefixed::iec(512.0).with_unit("B").to_string(), "512 B"];
+/// assert_eq![Prefixed::si(3_210_000.0).to_string(), "3.2 M"];
+/// assert_eq![Prefixed::si(-999_999.0).with_unit("W").to_string(), "-1 MW"];
+/// assert_eq![Prefixed::si(1234.56).with_decimals(3).to_string(), "1.235 k"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Prefixed<'a> {
+    value: f64,
+    binary: bool,
+    decimals: usize,
+    unit: &'a str,
+}
+
+impl<'a> Prefixed<'a> {
+    /// Returns a new number with decimal SI prefixes (`k`, `M`, `G`, …).
+    #[inline]
+    pub const fn si(value: f64) -> Self {
+        Self {
+            value,
+            binary: false,
+            decimals: 1,
+            unit: "",
+        }
+    }
+
+    /// Returns a new number with binary IEC prefixes (`Ki`, `Mi`, `Gi`, …).
+    #[inline]
+    pub const fn iec(value: f64) -> Self {
+        Self {
+            value,
+            binary: true,
+            decimals: 1,
+            unit: "",
+        }
+    }
+
+    /// Sets the maximum number of decimals.
+    #[inline]
+    #[must_use]
+    pub const fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Sets the unit written after the prefix.
+    #[inline]
+    #[must_use]
+    pub const fn with_unit(mut self, unit: &'a str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Returns the scaled absolute value and the symbol of its prefix.
+    fn scale(&self) -> (f64, &'static str) {
+        let (base, prefixes) = if self.binary {
+            (1024.0, &IEC_PREFIXES[..])
+        } else {
+            (1000.0, &SI_PREFIXES[..])
+        };
+        // the numbers that would be rounded up to the base also scale,
+        // while the infinities and NaN are left as they are
+        let half_ulp = (0..self.decimals).fold(0.5, |h, _| h / 10.0);
+        // clears the sign bit, since `f64::abs` is not in `core`
+        let magnitude = f64::from_bits(self.value.to_bits() & !(1 << 63));
+        let (mut value, mut prefix) = (magnitude, 0);
+        while value.is_finite() && prefix + 1 < prefixes.len() && value >= base - half_ulp {
+            value /= base;
+            prefix += 1;
+        }
+        (value, prefixes[prefix])
+    }
+}
+
+impl fmt::Display for Prefixed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (value, prefix) = self.scale();
+        pad(f, |writer| {
+            if self.value.is_sign_negative() && !self.value.is_nan() && value != 0.0 {
+                writer.write_char('-')?;
+            }
+            write!(TrimZeros::new(&mut *writer), "{:.*}", self.decimals, value)?;
+            if !prefix.is_empty() || !self.unit.is_empty() {
+                write!(writer, " {prefix}{}", self.unit)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// A writer adapter that removes the trailing zeros of the decimals
+/// of a formatted number, and its decimal point if they're all zeros.
+///
+/// The zeros are held back until a non-zero digit follows them.
+struct TrimZeros<W: fmt::Write> {
+    writer: W,
+    in_decimals: bool,
+    /// Whether the decimal point is held back.
+    point: bool,
+    /// The number of zeros held back.
+    zeros: usize,
+}
+
+impl<W: fmt::Write> TrimZeros<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            in_decimals: false,
+            point: false,
+            zeros: 0,
+        }
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for TrimZeros<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if !self.in_decimals && c == '.' {
+                self.in_decimals = true;
+                self.point = true;
+            } else if self.in_decimals && c == '0' {
+                self.zeros += 1;
+            } else if self.in_decimals && c.is_ascii_digit() {
+                if core::mem::take(&mut self.point) {
+                    self.writer.write_char('.')?;
+                }
+                (0..core::mem::take(&mut self.zeros))
+                    .try_for_each(|_| self.writer.write_char('0'))?;
+                self.writer.write_char(c)?;
+            } else {
+                // the decimals end after their digits
+                self.in_decimals = false;
+                self.point = false;
+                self.zeros = 0;
+                self.writer.write_char(c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* duration */
+
+/// The units of a human-readable duration, in nanoseconds.
+const DURATION_UNITS: [(u128, &str); 7] = [
+    (86_400_000_000_000, "d"),
+    (3_600_000_000_000, "h"),
+    (60_000_000_000, "m"),
+    (1_000_000_000, "s"),
+    (1_000_000, "ms"),
+    (1_000, "µs"),
+    (1, "ns"),
+];
+
+/// A human-readable duration, like `1h 5m`.
+///
+/// It shows 2 consecutive units by default, starting by the biggest one
+/// that is not zero, and the smaller units are truncated.
+/// The units that are zero are omitted.
+///
+/// # Examples
+/// ```
+/// use core::time::Duration;
+/// use textos::fmt::HumanDuration;
+///
+/// assert_eq![HumanDuration::new(Duration::from_secs(3725)).to_string(), "1h 2m"];
+/// assert_eq![HumanDuration::new(Duration::from_secs(3605)).to_string(), "1h"];
+/// assert_eq![HumanDuration::new(Duration::from_millis(1500)).to_string(), "1s 500ms"];
+/// assert_eq![HumanDuration::new(Duration::from_secs(90061)).with_units(4).to_string(),
+///     "1d 1h 1m 1s"];
+/// assert_eq![HumanDuration::new(Duration::ZERO).to_string(), "0s"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HumanDuration {
+    duration: Duration,
+    units: usize,
+}
+
+impl HumanDuration {
+    /// Returns a new human-readable `duration`.
+    #[inline]
+    pub const fn new(duration: Duration) -> Self {
+        Self { duration, units: 2 }
+    }
+
+    /// Sets the number of consecutive `units` to show, at least 1.
+    #[inline]
+    #[must_use]
+    pub const fn with_units(mut self, units: usize) -> Self {
+        self.units = if units > 0 { units } else { 1 };
+        self
+    }
+}
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, |writer| {
+            let mut nanos = self.duration.as_nanos();
+            if nanos == 0 {
+                return writer.write_str("0s");
+            }
+            let first = DURATION_UNITS
+                .iter()
+                .position(|(unit, _)| nanos >= *unit)
+                .unwrap_or(0);
+            let mut separator = "";
+            for (unit, symbol) in DURATION_UNITS.iter().skip(first).take(self.units) {
+                let amount = nanos / unit;
+                nanos %= unit;
+                if amount > 0 {
+                    write!(writer, "{separator}{amount}{symbol}")?;
+                    separator = " ";
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/* ordinal */
+
+/// An integer with its English ordinal suffix, like `1st` or `12th`.
+///
+/// # Examples
+/// ```
+/// use textos::fmt::Ordinal;
+///
+/// let ordinals = [1, 2, 3, 4, 11, 12, 13, 21, 102, 111, -3].map(|n| Ordinal(n).to_string());
+/// assert_eq![ordinals, ["1st", "2nd", "3rd", "4th", "11th", "12th", "13th",
+///     "21st", "102nd", "111th", "-3rd"]];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ordinal<T: Integer>(pub T);
+
+impl<T: Integer> fmt::Display for Ordinal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, |writer| {
+            write!(writer, "{}{}", self.0, self.0.ordinal_suffix())
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn grouping() {
+        let g = |v: f64| Grouped::new(v).with_decimals(1).to_string();
+        assert_eq![g(0.0), "0.0"];
+        assert_eq![g(-999.96), "-1,000.0"];
+        assert_eq![g(123456.0), "123,456.0"];
+        assert_eq![g(f64::INFINITY), "inf"];
+        assert_eq![
+            Grouped::new(u128::MAX).with_group(0).to_string(),
+            u128::MAX.to_string()
+        ];
+        assert_eq![
+            Grouped::new(1234).with_separator('\u{202F}').to_string(),
+            "1\u{202F}234"
+        ];
+    }
+
+    #[test]
+    fn prefixes() {
+        assert_eq![Prefixed::si(999.96).to_string(), "1 k"];
+        assert_eq![Prefixed::si(999.94).to_string(), "999.9"];
+        assert_eq![Prefixed::si(0.26).with_unit("m").to_string(), "0.3 m"];
+        assert_eq![
+            Prefixed::iec((0..9).fold(1.0, |v, _| v * 1024.0)).to_string(),
+            "1024 Yi"
+        ];
+        assert_eq![Prefixed::iec(-0.0).to_string(), "0"];
+        assert_eq![Prefixed::si(f64::NAN).to_string(), "NaN"];
+        assert_eq![Prefixed::si(-f64::NAN).to_string(), "NaN"];
+        assert_eq![
+            Prefixed::si(f64::INFINITY).with_unit("B").to_string(),
+            "inf B"
+        ];
+        assert_eq![Prefixed::iec(f64::NEG_INFINITY).to_string(), "-inf"];
+        // too long for a small buffer, with 271 integer digits
+        let huge = Prefixed::si(1e300).to_string();
+        assert![huge.len() == 271 + 2 && huge.ends_with(" Q") && !huge.contains('.')];
+        assert_eq![Prefixed::si(1.05).with_decimals(3).to_string(), "1.05"];
+        assert_eq![Prefixed::si(100.0).with_decimals(3).to_string(), "100"];
+    }
+
+    #[test]
+    fn padding() {
+        assert_eq![format!("{:8}", Grouped::new(1234)), "   1,234"];
+        assert_eq![format!("{:<8}|", Grouped::new(-1234)), "-1,234  |"];
+        assert_eq![format!("{:*^9}", Prefixed::si(1500.0)), "**1.5 k**"];
+        assert_eq![format!("{:2}", Prefixed::si(1500.0)), "1.5 k"];
+        assert_eq![format!("{:>5}", Ordinal(2)), "  2nd"];
+        assert_eq![
+            format!("{:-<6}", HumanDuration::new(Duration::ZERO)),
+            "0s----"
+        ];
+    }
+
+    #[test]
+    fn durations() {
+        let d = |nanos| HumanDuration::new(Duration::from_nanos(nanos)).to_string();
+        assert_eq![d(1), "1ns"];
+        assert_eq![d(1_001_000), "1ms 1µs"];
+        assert_eq![d(86_400_000_000_000 + 1), "1d"];
+    }
+}
@@ -0,0 +1,536 @@
+// textos::fmt::numeral
+//
+//! Numeral systems.
+//!
+//! Roman numerals, English number words, and decimal digits of other scripts.
+//
+
+use crate::{
+    error::{TextosError as Error, TextosResult as Result},
+    unicode::{number::parse_decimal, string::StaticU8String},
+};
+use core::fmt::{self, Write};
+
+/* roman */
+
+/// The Roman numeral symbols, and their values, from the biggest.
+const ROMAN: [(u16, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// A Roman numeral, from 1 to 3999.
+///
+/// It's displayed in upper case by default.
+///
+/// # Examples
+/// ```
+/// use textos::fmt::Roman;
+///
+/// assert_eq![Roman::new(1994).unwrap().to_string(), "MCMXCIV"];
+/// assert_eq![Roman::new(14).unwrap().lowercase().to_string(), "xiv"];
+/// assert_eq![Roman::parse("mmxxiv").unwrap().value(), 2024];
+/// assert![Roman::new(4000).is_err()];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Roman {
+    value: u16,
+    lowercase: bool,
+}
+
+impl Roman {
+    /// The biggest value that can be represented.
+    pub const MAX: u16 = 3999;
+
+    /// Returns a new upper case Roman numeral with the given `value`.
+    ///
+    /// # Errors
+    /// Returns [`OutOfBounds`][Error::OutOfBounds] if the value is 0 or > 3999.
+    #[inline]
+    pub const fn new(value: u16) -> Result<Self> {
+        if value == 0 || value > Self::MAX {
+            Err(Error::OutOfBounds)
+        } else {
+            Ok(Self {
+                value,
+                lowercase: false,
+            })
+        }
+    }
+
+    /// Returns the numeral in lower case.
+    #[inline]
+    #[must_use]
+    pub const fn lowercase(mut self) -> Self {
+        self.lowercase = true;
+        self
+    }
+
+    /// Returns the numeral in upper case.
+    #[inline]
+    #[must_use]
+    pub const fn uppercase(mut self) -> Self {
+        self.lowercase = false;
+        self
+    }
+
+    /// Returns the value of the numeral.
+    #[inline]
+    pub const fn value(self) -> u16 {
+        self.value
+    }
+
+    /// Parses a Roman numeral in upper or lower case.
+    ///
+    /// Only the canonical forms are accepted, so `IIII` or `IC` are not valid.
+    ///
+    /// # Errors
+    /// Returns [`InvalidNumber`][Error::InvalidNumber] with the byte position
+    /// of the first symbol that can't be parsed, or with position 0 if the
+    /// `text` is not a canonical Roman numeral as a whole.
+    pub fn parse(text: &str) -> Result<Self> {
+        let bytes = text.as_bytes();
+        let (mut value, mut index) = (0_u16, 0);
+        for (symbol_value, symbol) in ROMAN {
+            while bytes.len() >= index + symbol.len()
+                && bytes[index..index + symbol.len()].eq_ignore_ascii_case(symbol.as_bytes())
+            {
+                value = value
+                    .checked_add(symbol_value)
+                    .ok_or(Error::InvalidNumber { position: index })?;
+                index += symbol.len();
+            }
+        }
+        if index != bytes.len() {
+            return Err(Error::InvalidNumber { position: index });
+        }
+        // reject the non-canonical forms, like `IIII` or `XCX`
+        let not_canonical = Error::InvalidNumber { position: 0 };
+        let canonical = Roman::new(value).map_err(|_| not_canonical)?;
+        let mut rendered = StaticU8String::<16>::new();
+        write!(rendered, "{canonical}").map_err(|_| not_canonical)?;
+        if rendered.as_str().eq_ignore_ascii_case(text) {
+            Ok(canonical)
+        } else {
+            Err(not_canonical)
+        }
+    }
+}
+
+impl fmt::Display for Roman {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.value;
+        for (symbol_value, symbol) in ROMAN {
+            while value >= symbol_value {
+                value -= symbol_value;
+                for c in symbol.chars() {
+                    f.write_char(if self.lowercase {
+                        c.to_ascii_lowercase()
+                    } else {
+                        c
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/* english words */
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// Calls `emit` with each word of the English cardinal of `value`,
+/// and the separator that goes before it.
+fn for_each_word(
+    value: i128,
+    mut emit: impl FnMut(&'static str, &'static str) -> fmt::Result,
+) -> fmt::Result {
+    if value == 0 {
+        return emit("", ONES[0]);
+    }
+    let mut sep = "";
+    if value < 0 {
+        emit(sep, "minus")?;
+        sep = " ";
+    }
+    // split the magnitude in groups of 3 digits, from the lowest
+    let mut groups = [0_u16; SCALES.len()];
+    let mut magnitude = value.unsigned_abs();
+    for group in groups.iter_mut() {
+        *group = (magnitude % 1000) as u16;
+        magnitude /= 1000;
+    }
+    for (scale, &group) in groups.iter().enumerate().rev().filter(|(_, g)| **g > 0) {
+        let (hundreds, rest) = (group / 100, group % 100);
+        if hundreds > 0 {
+            emit(sep, ONES[hundreds as usize])?;
+            emit(" ", "hundred")?;
+            sep = " ";
+        }
+        match rest {
+            0 => (),
+            1..=19 => emit(sep, ONES[rest as usize])?,
+            _ => {
+                emit(sep, TENS[rest as usize / 10])?;
+                if rest % 10 > 0 {
+                    emit("-", ONES[rest as usize % 10])?;
+                }
+            }
+        }
+        sep = " ";
+        if scale > 0 {
+            emit(sep, SCALES[scale])?;
+        }
+    }
+    Ok(())
+}
+
+/// An integer spelled as an English cardinal number, like `forty-two`.
+///
+/// # Examples
+/// ```
+/// use textos::fmt::SpelledCardinal;
+///
+/// assert_eq![SpelledCardinal(42).to_string(), "forty-two"];
+/// assert_eq![SpelledCardinal(-1_000_017).to_string(), "minus one million seventeen"];
+/// assert_eq![SpelledCardinal(1_230).to_string(), "one thousand two hundred thirty"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpelledCardinal(pub i128);
+
+impl fmt::Display for SpelledCardinal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for_each_word(self.0, |sep, word| {
+            f.write_str(sep)?;
+            f.write_str(word)
+        })
+    }
+}
+
+/// An integer spelled as an English ordinal number, like `forty-second`.
+///
+/// # Examples
+/// ```
+/// use textos::fmt::SpelledOrdinal;
+///
+/// assert_eq![SpelledOrdinal(42).to_string(), "forty-second"];
+/// assert_eq![SpelledOrdinal(90).to_string(), "ninetieth"];
+/// assert_eq![SpelledOrdinal(112).to_string(), "one hundred twelfth"];
+/// assert_eq![SpelledOrdinal(2_000_000).to_string(), "two millionth"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpelledOrdinal(pub i128);
+
+impl fmt::Display for SpelledOrdinal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the last word is held back until the end, to make it ordinal
+        let mut last: Option<(&str, &str)> = None;
+        for_each_word(self.0, |sep, word| {
+            if let Some((sep, word)) = last.replace((sep, word)) {
+                f.write_str(sep)?;
+                f.write_str(word)?;
+            }
+            Ok(())
+        })?;
+        let Some((sep, word)) = last else {
+            return Ok(());
+        };
+        f.write_str(sep)?;
+        match word {
+            "one" => f.write_str("first"),
+            "two" => f.write_str("second"),
+            "three" => f.write_str("third"),
+            "five" => f.write_str("fifth"),
+            "eight" => f.write_str("eighth"),
+            "nine" => f.write_str("ninth"),
+            "twelve" => f.write_str("twelfth"),
+            _ if word.ends_with('y') => {
+                f.write_str(&word[..word.len() - 1])?;
+                f.write_str("ieth")
+            }
+            _ => {
+                f.write_str(word)?;
+                f.write_str("th")
+            }
+        }
+    }
+}
+
+/* digit sets */
+
+/// A set of decimal digits of a Unicode script.
+///
+/// The numbers are parsed by [`parse_decimal`][crate::unicode::number::parse_decimal],
+/// which knows the decimal digits of all the scripts.
+///
+/// # Examples
+/// ```
+/// use textos::fmt::{DigitSet, Grouped};
+///
+/// assert_eq![DigitSet::ArabicIndic.digits(2024).to_string(), "٢٠٢٤"];
+/// assert_eq![DigitSet::Devanagari.digits(Grouped::new(12345)).to_string(), "१२,३४५"];
+/// assert_eq![DigitSet::parse("２０２４").unwrap(), 2024];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DigitSet {
+    /// ASCII digits: `0123456789`.
+    Ascii,
+    /// Arabic-Indic digits: `٠١٢٣٤٥٦٧٨٩`.
+    ArabicIndic,
+    /// Extended Arabic-Indic digits, used in Persian and Urdu: `۰۱۲۳۴۵۶۷۸۹`.
+    ExtendedArabicIndic,
+    /// Devanagari digits: `०१२३४५६७८९`.
+    Devanagari,
+    /// Bengali digits: `০১২৩৪৫৬৭৮৯`.
+    Bengali,
+    /// Thai digits: `๐๑๒๓๔๕๖๗๘๙`.
+    Thai,
+    /// Fullwidth digits: `０１２３４５６７８９`.
+    Fullwidth,
+}
+
+impl DigitSet {
+    /// All the digit sets.
+    pub const ALL: [Self; 7] = [
+        Self::Ascii,
+        Self::ArabicIndic,
+        Self::ExtendedArabicIndic,
+        Self::Devanagari,
+        Self::Bengali,
+        Self::Thai,
+        Self::Fullwidth,
+    ];
+
+    /// Returns the digit zero of the set. The rest of the digits follow it.
+    #[inline]
+    pub const fn zero(self) -> char {
+        match self {
+            DigitSet::Ascii => '0',
+            DigitSet::ArabicIndic => '\u{0660}',
+            DigitSet::ExtendedArabicIndic => '\u{06F0}',
+            DigitSet::Devanagari => '\u{0966}',
+            DigitSet::Bengali => '\u{09E6}',
+            DigitSet::Thai => '\u{0E50}',
+            DigitSet::Fullwidth => '\u{FF10}',
+        }
+    }
+
+    /// Returns the character of the given `digit`, or `None` if it's > 9.
+    #[inline]
+    pub const fn digit(self, digit: u32) -> Option<char> {
+        if digit < 10 {
+            char::from_u32(self.zero() as u32 + digit)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the set of the digit `character`, or `None` if it's not a digit
+    /// of any of the sets.
+    pub const fn of(character: char) -> Option<Self> {
+        let mut i = 0;
+        while i < Self::ALL.len() {
+            let zero = Self::ALL[i].zero() as u32;
+            if character as u32 >= zero && (character as u32) < zero + 10 {
+                return Some(Self::ALL[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the value of the digit `character` of any of the sets,
+    /// or `None` if it's not a digit.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::fmt::DigitSet;
+    ///
+    /// assert_eq![Some(7), DigitSet::to_digit('٧')];
+    /// assert_eq![None, DigitSet::to_digit('a')];
+    /// ```
+    pub fn to_digit(character: char) -> Option<u32> {
+        let set = Self::of(character)?;
+        Some(character as u32 - set.zero() as u32)
+    }
+
+    /// Returns a wrapper that displays the `value` with the ASCII digits
+    /// replaced by the digits of this set.
+    #[inline]
+    pub const fn digits<T: fmt::Display>(self, value: T) -> Digits<T> {
+        Digits { value, set: self }
+    }
+
+    /// Parses an unsigned integer written with the digits of one of the sets.
+    ///
+    /// It's like [`parse_decimal::<u64>`][parse_decimal], so all the digits
+    /// must belong to the same set, but without a sign.
+    ///
+    /// # Errors
+    /// Returns [`InvalidNumber`][Error::InvalidNumber] with the byte position
+    /// of the first character that is not a digit of the set of the first one,
+    /// or 0 if the `text` is empty, or [`NumberOverflow`][Error::NumberOverflow]
+    /// with the byte position of the digit that doesn't fit.
+    pub fn parse(text: &str) -> Result<u64> {
+        if let Some((position, _)) = text.char_indices().find(|(_, c)| Self::of(*c).is_none()) {
+            return Err(Error::InvalidNumber { position });
+        }
+        parse_decimal::<u64>(text)
+    }
+}
+
+/// Displays a value with its ASCII digits replaced by the digits of a [`DigitSet`].
+///
+/// It's returned by [`DigitSet::digits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Digits<T: fmt::Display> {
+    value: T,
+    set: DigitSet,
+}
+
+impl<T: fmt::Display> fmt::Display for Digits<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut writer = DigitWriter {
+            writer: f,
+            set: self.set,
+        };
+        write!(writer, "{}", self.value)
+    }
+}
+
+/// A writer adapter that replaces the ASCII digits.
+struct DigitWriter<W: fmt::Write> {
+    writer: W,
+    set: DigitSet,
+}
+
+impl<W: fmt::Write> fmt::Write for DigitWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let c = c.to_digit(10).and_then(|d| self.set.digit(d)).unwrap_or(c);
+            self.writer.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec::Vec};
+
+    #[test]
+    fn roman_round_trip() {
+        for n in 1..=Roman::MAX {
+            let roman = Roman::new(n).unwrap();
+            assert_eq![n, Roman::parse(&roman.to_string()).unwrap().value()];
+            assert_eq![
+                n,
+                Roman::parse(&roman.lowercase().to_string())
+                    .unwrap()
+                    .value()
+            ];
+        }
+        for invalid in ["", "IIII", "IC", "VV", "IVI", "XCX", "MMMM", "ABC"] {
+            assert![Roman::parse(invalid).is_err(), "{invalid}"];
+        }
+    }
+
+    #[test]
+    fn words() {
+        assert_eq![SpelledCardinal(0).to_string(), "zero"];
+        assert_eq![SpelledOrdinal(0).to_string(), "zeroth"];
+        assert_eq![SpelledOrdinal(-21).to_string(), "minus twenty-first"];
+        assert_eq![SpelledOrdinal(100).to_string(), "one hundredth"];
+        assert_eq![
+            SpelledCardinal(i128::MIN)
+                .to_string()
+                .split(' ')
+                .take(4)
+                .collect::<Vec<_>>(),
+            ["minus", "one", "hundred", "seventy"]
+        ];
+    }
+
+    #[test]
+    fn digit_sets() {
+        for set in DigitSet::ALL {
+            for d in 0..10 {
+                assert_eq![Some(d), DigitSet::to_digit(set.digit(d).unwrap())];
+            }
+        }
+        assert_eq![DigitSet::parse("١٢٣").unwrap(), 123];
+        assert_eq![
+            DigitSet::parse("1٢३"),
+            Err(Error::InvalidNumber { position: 1 })
+        ];
+        assert_eq![
+            DigitSet::parse("+1"),
+            Err(Error::InvalidNumber { position: 0 })
+        ];
+        assert_eq![
+            DigitSet::parse("18446744073709551616"),
+            Err(Error::NumberOverflow { position: 19 })
+        ];
+        assert_eq![
+            DigitSet::parse("１x"),
+            Err(Error::InvalidNumber { position: 3 })
+        ];
+        assert_eq![
+            DigitSet::parse(""),
+            Err(Error::InvalidNumber { position: 0 })
+        ];
+    }
+}
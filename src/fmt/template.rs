@@ -0,0 +1,553 @@
+// textos::fmt::template
+//
+//! Runtime templates.
+//
+
+use super::Alignment;
+use crate::{
+    error::{TextosError as Error, TextosResult as Result},
+    unicode::{string::StaticU8String, width::str_width},
+};
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, string::String};
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow;
+use core::fmt::{self, Write};
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+/// A text with placeholders that are filled at runtime.
+///
+/// The syntax is a subset of the one of [`format!`]:
+/// - `{name}` is replaced by the argument with that name.
+/// - `{0}` is replaced by the argument at that position.
+/// - `{}` is replaced by the next positional argument,
+///   not counting the ones with an explicit position.
+/// - `{{` and `}}` are replaced by `{` and `}`.
+///
+/// Each placeholder can have a format spec after a colon, made of an optional
+/// fill character followed by an alignment (`<`, `^` or `>`), and a minimum
+/// width, like `{name:*^10}`. The width is measured in display columns,
+/// so wide characters count as 2, and the values are left aligned and padded
+/// with spaces by default.
+///
+/// The arguments are given by any [`TemplateArgs`] implementor.
+///
+/// # Examples
+/// ```
+/// use core::fmt::Display;
+/// use textos::fmt::Template;
+///
+/// let template = Template::new("Hello {name}, you have {count:>3} items").unwrap();
+/// let args: [(&str, &dyn Display); 2] = [("name", &"Ana"), ("count", &7)];
+///
+/// let mut s = String::new();
+/// template.write(&args, &mut s).unwrap();
+/// assert_eq![s, "Hello Ana, you have   7 items"];
+///
+/// let positional: [&dyn Display; 2] = [&"x", &1.5];
+/// let template = Template::new("{{{:-<4}}} {1} {0}").unwrap();
+/// assert_eq![template.display(&positional).unwrap().to_string(), "{x---} 1.5 x"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Template<'a> {
+    text: &'a str,
+}
+
+/// The key of a template placeholder.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TemplateKey<'a> {
+    /// The position of the argument.
+    Index(usize),
+    /// The name of the argument.
+    Name(&'a str),
+}
+
+/// The arguments for filling a [`Template`].
+///
+/// It's implemented for:
+/// - slices and arrays of `(&str, &dyn Display)`, by name and by position.
+/// - slices and arrays of `&dyn Display`, by position.
+/// - `BTreeMap` and `HashMap` with string keys, by name.
+pub trait TemplateArgs {
+    /// Returns the argument for the `key`, if there's any.
+    fn arg(&self, key: TemplateKey<'_>) -> Option<&dyn fmt::Display>;
+}
+
+impl<'a> Template<'a> {
+    /// Returns a new template from the given `text`.
+    ///
+    /// # Errors
+    /// Returns [`InvalidEscape`][Error::InvalidEscape] with the byte position
+    /// of the first closing brace that is neither escaped nor closing a placeholder,
+    /// or [`InvalidTemplate`][Error::InvalidTemplate] with the byte position
+    /// of the first syntax error in a placeholder, like an unclosed one.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::{error::TextosError, fmt::Template};
+    ///
+    /// assert![Template::new("{a} {0:_>5} {{}}").is_ok()];
+    /// assert![matches![Template::new("ok {a"), Err(TextosError::InvalidTemplate { position: 3 })]];
+    /// assert![matches![Template::new("a}"), Err(TextosError::InvalidEscape { position: 1 })]];
+    /// assert![matches![Template::new("{a:<x}"), Err(TextosError::InvalidTemplate { position: 4 })]];
+    /// ```
+    pub fn new(text: &'a str) -> Result<Self> {
+        Pieces::new(text).try_for_each(|piece| piece.map(|_| ()))?;
+        Ok(Self { text })
+    }
+
+    /// Returns the text of the template.
+    #[inline]
+    pub const fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    //
+
+    /// Checks that all the placeholders have an argument.
+    ///
+    /// # Errors
+    /// Returns [`MissingArgument`][Error::MissingArgument] with the byte
+    /// position of the first placeholder without an argument.
+    pub fn check<A: TemplateArgs + ?Sized>(&self, args: &A) -> Result<()> {
+        for piece in Pieces::new(self.text) {
+            if let Piece::Placeholder(p) = piece? {
+                if args.arg(p.key).is_none() {
+                    return Err(Error::MissingArgument {
+                        position: p.position,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the template filled with the `args` into the `writer`.
+    ///
+    /// The arguments are checked before writing anything.
+    ///
+    /// # Errors
+    /// Returns [`MissingArgument`][Error::MissingArgument] with the byte
+    /// position of the first placeholder without an argument,
+    /// or [`Fmt`][Error::Fmt] if the writer fails.
+    pub fn write<A: TemplateArgs + ?Sized, W: fmt::Write>(
+        &self,
+        args: &A,
+        writer: &mut W,
+    ) -> Result<()> {
+        self.check(args)?;
+        Ok(self.render(args, writer)?)
+    }
+
+    /// Returns a wrapper that displays the template filled with the `args`.
+    ///
+    /// The arguments are checked before returning the wrapper.
+    ///
+    /// # Errors
+    /// Returns [`MissingArgument`][Error::MissingArgument] with the byte
+    /// position of the first placeholder without an argument.
+    #[inline]
+    pub fn display<'b, A: TemplateArgs + ?Sized>(
+        &'b self,
+        args: &'b A,
+    ) -> Result<TemplateDisplay<'b, A>> {
+        self.check(args)?;
+        Ok(TemplateDisplay {
+            template: self,
+            args,
+        })
+    }
+
+    /// Returns the template filled with the `args`.
+    ///
+    /// # Errors
+    /// Returns [`MissingArgument`][Error::MissingArgument] with the byte
+    /// position of the first placeholder without an argument.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use textos::{error::TextosError, fmt::Template};
+    ///
+    /// let args = BTreeMap::from([("user", "ana"), ("host", "example.org")]);
+    /// let template = Template::new("{user}@{host}").unwrap();
+    /// assert_eq![template.fill(&args).unwrap(), "ana@example.org"];
+    ///
+    /// let template = Template::new("{user}@{domain}").unwrap();
+    /// assert![matches![template.fill(&args), Err(TextosError::MissingArgument { position: 7 })]];
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    pub fn fill<A: TemplateArgs + ?Sized>(&self, args: &A) -> Result<String> {
+        self.check(args)?;
+        let mut string = String::with_capacity(self.text.len());
+        // writing into a String can't fail
+        let _ = self.render(args, &mut string);
+        Ok(string)
+    }
+
+    /// Returns the template filled with the `args`, in a static string.
+    ///
+    /// # Errors
+    /// Returns [`MissingArgument`][Error::MissingArgument] with the byte
+    /// position of the first placeholder without an argument, or
+    /// [`NotEnoughCapacity`][Error::NotEnoughCapacity] with the needed
+    /// capacity if the result doesn't fit in `CAP` bytes.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Display;
+    /// use textos::fmt::Template;
+    ///
+    /// let template = Template::new("[{:^7}]").unwrap();
+    /// let args: [&dyn Display; 1] = [&"ok"];
+    /// assert_eq![template.fill_static::<16, _>(&args).unwrap(), "[  ok   ]"];
+    /// assert![template.fill_static::<8, _>(&args).is_err()];
+    /// ```
+    pub fn fill_static<const CAP: usize, A: TemplateArgs + ?Sized>(
+        &self,
+        args: &A,
+    ) -> Result<StaticU8String<CAP>> {
+        self.check(args)?;
+        StaticU8String::from_render(|mut w| self.render(args, &mut w))
+    }
+
+    /// Writes the template filled with the `args`, failing on missing arguments.
+    fn render<A: TemplateArgs + ?Sized, W: fmt::Write>(
+        &self,
+        args: &A,
+        writer: &mut W,
+    ) -> fmt::Result {
+        for piece in Pieces::new(self.text) {
+            match piece.map_err(|_| fmt::Error)? {
+                Piece::Literal(text) => writer.write_str(text)?,
+                Piece::Placeholder(p) => {
+                    let value = args.arg(p.key).ok_or(fmt::Error)?;
+                    p.render(value, writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Displays a [`Template`] filled with its arguments.
+///
+/// It's returned by [`Template::display`], once the arguments are checked.
+pub struct TemplateDisplay<'a, A: TemplateArgs + ?Sized> {
+    template: &'a Template<'a>,
+    args: &'a A,
+}
+
+impl<A: TemplateArgs + ?Sized> fmt::Display for TemplateDisplay<'_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.template.render(self.args, f)
+    }
+}
+
+/* parsing */
+
+/// A parsed piece of a template.
+enum Piece<'a> {
+    Literal(&'a str),
+    Placeholder(Placeholder<'a>),
+}
+
+/// A parsed placeholder.
+struct Placeholder<'a> {
+    /// The byte position of the opening brace.
+    position: usize,
+    key: TemplateKey<'a>,
+    fill: char,
+    alignment: Alignment,
+    width: usize,
+}
+
+impl Placeholder<'_> {
+    /// Writes the `value` padded to the width.
+    fn render<W: fmt::Write>(&self, value: &dyn fmt::Display, writer: &mut W) -> fmt::Result {
+        if self.width > 0 {
+            let mut counter = WidthCounter(0);
+            write!(counter, "{value}")?;
+            let (left, right) = self.alignment.split(self.width.saturating_sub(counter.0));
+            (0..left).try_for_each(|_| writer.write_char(self.fill))?;
+            write!(writer, "{value}")?;
+            (0..right).try_for_each(|_| writer.write_char(self.fill))
+        } else {
+            write!(writer, "{value}")
+        }
+    }
+}
+
+/// An iterator over the pieces of a template.
+struct Pieces<'a> {
+    text: &'a str,
+    offset: usize,
+    /// The index of the next implicit positional argument.
+    next_index: usize,
+}
+
+impl<'a> Pieces<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            offset: 0,
+            next_index: 0,
+        }
+    }
+
+    /// Parses the placeholder at the start of `rest`, which ends at `end`.
+    fn placeholder(&mut self, rest: &'a str, end: usize) -> Result<Placeholder<'a>> {
+        let position = self.offset;
+        let invalid = |i: usize| Error::InvalidTemplate {
+            position: position + i,
+        };
+        let inner = &rest[1..end];
+        if let Some(i) = inner.find('{') {
+            return Err(invalid(1 + i));
+        }
+        let (key, spec) = inner.split_once(':').unwrap_or((inner, ""));
+        let key = if key.is_empty() {
+            self.next_index += 1;
+            TemplateKey::Index(self.next_index - 1)
+        } else if key.bytes().all(|b| b.is_ascii_digit()) {
+            TemplateKey::Index(key.parse().map_err(|_| invalid(1))?)
+        } else {
+            TemplateKey::Name(key)
+        };
+
+        // [[fill]align][width]
+        let spec_start = 1 + inner.len() - spec.len();
+        let align = |c| match c {
+            '<' => Some(Alignment::Left),
+            '^' => Some(Alignment::Center),
+            '>' => Some(Alignment::Right),
+            _ => None,
+        };
+        let mut chars = spec.chars();
+        let (fill, alignment, width) = match (chars.next(), chars.next().and_then(align)) {
+            (Some(fill), Some(alignment)) => (fill, alignment, chars.as_str()),
+            (Some(c), _) if align(c).is_some() => (' ', align(c).unwrap_or_default(), &spec[1..]),
+            _ => (' ', Alignment::Left, spec),
+        };
+        let width_start = spec_start + spec.len() - width.len();
+        if let Some(i) = width.find(|c: char| !c.is_ascii_digit()) {
+            return Err(invalid(width_start + i));
+        }
+        let width = if width.is_empty() {
+            0
+        } else {
+            width.parse().map_err(|_| invalid(width_start))?
+        };
+        Ok(Placeholder {
+            position,
+            key,
+            fill,
+            alignment,
+            width,
+        })
+    }
+}
+
+impl<'a> Iterator for Pieces<'a> {
+    type Item = Result<Piece<'a>>;
+
+    fn next(&mut self) -> Option<Result<Piece<'a>>> {
+        let rest = &self.text[self.offset..];
+        if rest.is_empty() {
+            return None;
+        }
+        let brace = rest.find(['{', '}']).unwrap_or(rest.len());
+        if brace > 0 {
+            self.offset += brace;
+            return Some(Ok(Piece::Literal(&rest[..brace])));
+        }
+        let result = if rest.starts_with("{{") || rest.starts_with("}}") {
+            self.offset += 2;
+            return Some(Ok(Piece::Literal(&rest[..1])));
+        } else if rest.starts_with('}') {
+            // a lone closing brace
+            Err(Error::InvalidEscape {
+                position: self.offset,
+            })
+        } else if let Some(end) = rest.find('}') {
+            let placeholder = self.placeholder(rest, end);
+            self.offset += end + 1;
+            placeholder.map(Piece::Placeholder)
+        } else {
+            // an opening brace that is never closed
+            Err(Error::InvalidTemplate {
+                position: self.offset,
+            })
+        };
+        if result.is_err() {
+            // stop after the first error
+            self.offset = self.text.len();
+        }
+        Some(result)
+    }
+}
+
+/// A writer that only counts the display width of the written text.
+struct WidthCounter(usize);
+
+impl fmt::Write for WidthCounter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += str_width(s);
+        Ok(())
+    }
+}
+
+/* arguments */
+
+impl<T: TemplateArgs + ?Sized> TemplateArgs for &T {
+    #[inline]
+    fn arg(&self, key: TemplateKey<'_>) -> Option<&dyn fmt::Display> {
+        (**self).arg(key)
+    }
+}
+
+impl<const N: usize, T> TemplateArgs for [T; N]
+where
+    [T]: TemplateArgs,
+{
+    #[inline]
+    fn arg(&self, key: TemplateKey<'_>) -> Option<&dyn fmt::Display> {
+        self.as_slice().arg(key)
+    }
+}
+
+impl TemplateArgs for [(&str, &dyn fmt::Display)] {
+    fn arg(&self, key: TemplateKey<'_>) -> Option<&dyn fmt::Display> {
+        match key {
+            TemplateKey::Index(i) => self.get(i).map(|(_, value)| *value),
+            TemplateKey::Name(name) => self.iter().find(|(n, _)| *n == name).map(|(_, v)| *v),
+        }
+    }
+}
+
+impl TemplateArgs for [&dyn fmt::Display] {
+    fn arg(&self, key: TemplateKey<'_>) -> Option<&dyn fmt::Display> {
+        match key {
+            TemplateKey::Index(i) => self.get(i).copied(),
+            TemplateKey::Name(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<K: Borrow<str> + Ord, V: fmt::Display> TemplateArgs for BTreeMap<K, V> {
+    fn arg(&self, key: TemplateKey<'_>) -> Option<&dyn fmt::Display> {
+        match key {
+            TemplateKey::Name(name) => self.get(name).map(|v| v as &dyn fmt::Display),
+            TemplateKey::Index(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+impl<K: Borrow<str> + Hash + Eq, V: fmt::Display, S: BuildHasher> TemplateArgs
+    for HashMap<K, V, S>
+{
+    fn arg(&self, key: TemplateKey<'_>) -> Option<&dyn fmt::Display> {
+        match key {
+            TemplateKey::Name(name) => self.get(name).map(|v| v as &dyn fmt::Display),
+            TemplateKey::Index(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn error_position(result: Result<String>) -> Option<usize> {
+        match result {
+            Err(
+                Error::InvalidEscape { position }
+                | Error::InvalidTemplate { position }
+                | Error::MissingArgument { position },
+            ) => Some(position),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn fills() {
+        let args: [(&str, &dyn fmt::Display); 3] = [("a", &1), ("b", &"two"), ("c", &'π')];
+        let fill = |t: &str| Template::new(t).and_then(|t| t.fill(&args));
+
+        assert_eq![fill("").unwrap(), ""];
+        assert_eq![fill("plain").unwrap(), "plain"];
+        assert_eq![fill("{a}{b}{c}").unwrap(), "1twoπ"];
+        assert_eq![fill("{}-{}-{0}-{}").unwrap(), "1-two-1-π"];
+        assert_eq![fill("{{a}} }}{{").unwrap(), "{a} }{"];
+        assert_eq![
+            fill("[{b:5}][{b:>5}][{b:^6}]").unwrap(),
+            "[two  ][  two][ two  ]"
+        ];
+        assert_eq![
+            fill("[{c:·^4}][{a:_>3}][{a:<<3}]").unwrap(),
+            "[·π··][__1][1<<]"
+        ];
+        assert_eq![fill("[{b:2}]").unwrap(), "[two]"];
+        // the wide characters take 2 columns
+        let wide: [(&str, &dyn fmt::Display); 1] = [("w", &"漢字")];
+        let template = Template::new("[{w:>6}]").unwrap();
+        assert_eq![template.fill(&wide).unwrap(), "[  漢字]"];
+
+        assert_eq![error_position(fill("{d}")), Some(0)];
+        assert_eq![error_position(fill("{a}{3}")), Some(3)];
+        assert_eq![error_position(fill("é{a")), Some(2)];
+        assert_eq![error_position(fill("{a{b}")), Some(2)];
+        assert![matches![
+            fill("a}b"),
+            Err(Error::InvalidEscape { position: 1 })
+        ]];
+        assert![matches![
+            fill("{{{"),
+            Err(Error::InvalidTemplate { position: 2 })
+        ]];
+        assert![matches![
+            fill("{a:>5x}"),
+            Err(Error::InvalidTemplate { position: 5 })
+        ]];
+        assert_eq![error_position(fill("{a:>5x}")), Some(5)];
+        assert_eq![error_position(fill("{a:^^x}")), Some(5)];
+        assert_eq![error_position(fill("{99999999999999999999999}")), Some(1)];
+    }
+
+    #[test]
+    fn display() {
+        let args: [&dyn fmt::Display; 1] = [&1];
+        let template = Template::new("{0}+{0}").unwrap();
+        assert_eq![template.display(&args).unwrap().to_string(), "1+1"];
+
+        let template = Template::new("{0} {1}").unwrap();
+        assert![matches![
+            template.display(&args),
+            Err(Error::MissingArgument { position: 4 })
+        ]];
+    }
+
+    #[test]
+    fn no_partial_output() {
+        let args: [&dyn fmt::Display; 1] = [&1];
+        let template = Template::new("{0} {1}").unwrap();
+        let mut s = String::new();
+        assert![template.write(&args, &mut s).is_err()];
+        assert![s.is_empty()];
+    }
+}
@@ -23,7 +23,7 @@ devela::deprecate_feature![old: "no-std", new: "no_std", since: "0.0.3"];
 
 pub mod error;
 
-mod ascii;
+pub mod ascii;
 pub mod fmt;
 pub(crate) mod macros;
 pub mod textual;
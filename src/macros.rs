@@ -23,3 +23,90 @@ macro_rules! impl_sized_alias {
     }};
 }
 pub(crate) use impl_sized_alias;
+
+/// implements content-based comparison and hashing traits for string types.
+///
+/// The type must have an `as_str` method and a `CAP` const generic argument.
+macro_rules! impl_cmp_str {
+    // $type: the name of the string type.
+    ($type:ident) => {
+        impl<const CAP: usize, const CAP2: usize> PartialEq<$type<CAP2>> for $type<CAP> {
+            #[inline]
+            fn eq(&self, other: &$type<CAP2>) -> bool {
+                self.as_str() == other.as_str()
+            }
+        }
+        impl<const CAP: usize> Eq for $type<CAP> {}
+
+        impl<const CAP: usize, const CAP2: usize> PartialOrd<$type<CAP2>> for $type<CAP> {
+            #[inline]
+            fn partial_cmp(&self, other: &$type<CAP2>) -> Option<core::cmp::Ordering> {
+                self.as_str().partial_cmp(other.as_str())
+            }
+        }
+        impl<const CAP: usize> Ord for $type<CAP> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.as_str().cmp(other.as_str())
+            }
+        }
+
+        impl<const CAP: usize> core::hash::Hash for $type<CAP> {
+            /// Hashes the string slice, so that it's consistent with [`Borrow<str>`].
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.as_str().hash(state);
+            }
+        }
+
+        impl<const CAP: usize> core::borrow::Borrow<str> for $type<CAP> {
+            #[inline]
+            fn borrow(&self) -> &str {
+                self.as_str()
+            }
+        }
+        impl<const CAP: usize> AsRef<str> for $type<CAP> {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl_cmp_str![@$type: str];
+        impl_cmp_str![@$type: &'a str, 'a];
+        #[cfg(feature = "alloc")]
+        impl_cmp_str![@$type: alloc::string::String];
+        #[cfg(feature = "alloc")]
+        impl_cmp_str![@$type: alloc::borrow::Cow<'a, str>, 'a];
+    };
+
+    // $other: the type to compare with in both directions.
+    // $lt: an optional lifetime for the other type.
+    (@$type:ident: $other:ty $(, $lt:lifetime)?) => {
+        impl<$($lt,)? const CAP: usize> PartialEq<$other> for $type<CAP> {
+            #[inline]
+            fn eq(&self, other: &$other) -> bool {
+                self.as_str() == &other[..]
+            }
+        }
+        impl<$($lt,)? const CAP: usize> PartialEq<$type<CAP>> for $other {
+            #[inline]
+            fn eq(&self, other: &$type<CAP>) -> bool {
+                &self[..] == other.as_str()
+            }
+        }
+        impl<$($lt,)? const CAP: usize> PartialOrd<$other> for $type<CAP> {
+            #[inline]
+            fn partial_cmp(&self, other: &$other) -> Option<core::cmp::Ordering> {
+                self.as_str().partial_cmp(&other[..])
+            }
+        }
+        impl<$($lt,)? const CAP: usize> PartialOrd<$type<CAP>> for $other {
+            #[inline]
+            fn partial_cmp(&self, other: &$type<CAP>) -> Option<core::cmp::Ordering> {
+                self[..].partial_cmp(other.as_str())
+            }
+        }
+    };
+}
+pub(crate) use impl_cmp_str;
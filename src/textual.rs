@@ -4,9 +4,19 @@
 //
 
 // use crate::error::TextosResult as Result;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
 use core::fmt::Display;
 
 /// Common trait for all string types.
 ///
 /// It depends on the [`ToString`] trait if the `alloc` feature is enabled.
 pub trait Textual: Display {}
+
+impl Textual for str {}
+impl<T: Textual + ?Sized> Textual for &T {}
+
+#[cfg(feature = "alloc")]
+impl Textual for String {}
+#[cfg(feature = "alloc")]
+impl Textual for Cow<'_, str> {}
@@ -1,6 +1,6 @@
 // textos::string::char::impls
 //
-//!
+//
 //
 // TOC
 // - common implementations
@@ -64,6 +64,10 @@ macro_rules! impls {
             #[inline]
             fn to_digit(self, radix: u32) -> Option<u32> { self.to_digit(radix) }
             #[inline]
+            fn to_decimal_digit_unicode(self) -> Option<u32> {
+                self.to_decimal_digit_unicode()
+            }
+            #[inline]
             fn to_ascii_uppercase(self) -> Self { self.to_ascii_uppercase() }
             #[inline]
             fn to_ascii_lowercase(self) -> Self { self.to_ascii_lowercase() }
@@ -74,6 +78,8 @@ macro_rules! impls {
             fn is_noncharacter(self) -> bool { self.is_noncharacter() }
             #[inline]
             fn is_digit(self, radix: u32) -> bool { self.is_digit(radix) }
+            #[inline]
+            fn is_decimal_digit_unicode(self) -> bool { self.is_decimal_digit_unicode() }
             //
             #[inline]
             fn is_control(self) -> bool { self.to_char().is_control() }
@@ -128,6 +134,18 @@ macro_rules! impls {
                 self.to_char().to_digit(radix)
             }
 
+            /// Converts the scalar to a decimal digit of any script.
+            ///
+            /// ‘Digit’ is defined to be any character of the `Nd` general category,
+            /// like `٣` or `３`, with its `Numeric_Value` from 0 to 9.
+            ///
+            /// # Errors
+            /// Returns None if the scalar is not a decimal digit.
+            #[inline]
+            pub const fn to_decimal_digit_unicode(self) -> Option<u32> {
+                decimal_digit(self.to_u32())
+            }
+
             /* queries */
 
             /// Returns `true` if this is the nul character (`0x00`).
@@ -141,6 +159,14 @@ macro_rules! impls {
             pub const fn is_digit(self, radix: u32) -> bool {
                 if let Some(_) = self.to_digit(radix) { true } else { false }
             }
+
+            /// Checks if the unicode scalar is a decimal digit of any script.
+            ///
+            /// See also [`to_decimal_digit_unicode`][Self#method.to_decimal_digit_unicode].
+            #[inline]
+            pub const fn is_decimal_digit_unicode(self) -> bool {
+                decimal_digit(self.to_u32()).is_some()
+            }
         }
     }};
 }
@@ -154,6 +180,75 @@ impl Char7 {
     /// The highest unicode scalar a `Char7` can represent, `'\u{7F}'`.
     pub const MAX: Char7 = Char7::new_unchecked(0x7F);
 
+    /* control characters */
+
+    /// Null, `'\u{00}'`.
+    pub const NUL: Char7 = Char7::new_unchecked(0x00);
+    /// Start of Heading, `'\u{01}'`.
+    pub const SOH: Char7 = Char7::new_unchecked(0x01);
+    /// Start of Text, `'\u{02}'`.
+    pub const STX: Char7 = Char7::new_unchecked(0x02);
+    /// End of Text, `'\u{03}'`.
+    pub const ETX: Char7 = Char7::new_unchecked(0x03);
+    /// End of Transmission, `'\u{04}'`.
+    pub const EOT: Char7 = Char7::new_unchecked(0x04);
+    /// Enquiry, `'\u{05}'`.
+    pub const ENQ: Char7 = Char7::new_unchecked(0x05);
+    /// Acknowledge, `'\u{06}'`.
+    pub const ACK: Char7 = Char7::new_unchecked(0x06);
+    /// Bell, `'\u{07}'`.
+    pub const BEL: Char7 = Char7::new_unchecked(0x07);
+    /// Backspace, `'\u{08}'`.
+    pub const BS: Char7 = Char7::new_unchecked(0x08);
+    /// Horizontal Tab, `'\u{09}'`.
+    pub const HT: Char7 = Char7::new_unchecked(0x09);
+    /// Line Feed, `'\u{0A}'`.
+    pub const LF: Char7 = Char7::new_unchecked(0x0A);
+    /// Vertical Tab, `'\u{0B}'`.
+    pub const VT: Char7 = Char7::new_unchecked(0x0B);
+    /// Form Feed, `'\u{0C}'`.
+    pub const FF: Char7 = Char7::new_unchecked(0x0C);
+    /// Carriage Return, `'\u{0D}'`.
+    pub const CR: Char7 = Char7::new_unchecked(0x0D);
+    /// Shift Out, `'\u{0E}'`.
+    pub const SO: Char7 = Char7::new_unchecked(0x0E);
+    /// Shift In, `'\u{0F}'`.
+    pub const SI: Char7 = Char7::new_unchecked(0x0F);
+    /// Data Link Escape, `'\u{10}'`.
+    pub const DLE: Char7 = Char7::new_unchecked(0x10);
+    /// Device Control 1 (XON), `'\u{11}'`.
+    pub const DC1: Char7 = Char7::new_unchecked(0x11);
+    /// Device Control 2, `'\u{12}'`.
+    pub const DC2: Char7 = Char7::new_unchecked(0x12);
+    /// Device Control 3 (XOFF), `'\u{13}'`.
+    pub const DC3: Char7 = Char7::new_unchecked(0x13);
+    /// Device Control 4, `'\u{14}'`.
+    pub const DC4: Char7 = Char7::new_unchecked(0x14);
+    /// Negative Acknowledge, `'\u{15}'`.
+    pub const NAK: Char7 = Char7::new_unchecked(0x15);
+    /// Synchronous Idle, `'\u{16}'`.
+    pub const SYN: Char7 = Char7::new_unchecked(0x16);
+    /// End of Transmission Block, `'\u{17}'`.
+    pub const ETB: Char7 = Char7::new_unchecked(0x17);
+    /// Cancel, `'\u{18}'`.
+    pub const CAN: Char7 = Char7::new_unchecked(0x18);
+    /// End of Medium, `'\u{19}'`.
+    pub const EM: Char7 = Char7::new_unchecked(0x19);
+    /// Substitute, `'\u{1A}'`.
+    pub const SUB: Char7 = Char7::new_unchecked(0x1A);
+    /// Escape, `'\u{1B}'`.
+    pub const ESC: Char7 = Char7::new_unchecked(0x1B);
+    /// File Separator, `'\u{1C}'`.
+    pub const FS: Char7 = Char7::new_unchecked(0x1C);
+    /// Group Separator, `'\u{1D}'`.
+    pub const GS: Char7 = Char7::new_unchecked(0x1D);
+    /// Record Separator, `'\u{1E}'`.
+    pub const RS: Char7 = Char7::new_unchecked(0x1E);
+    /// Unit Separator, `'\u{1F}'`.
+    pub const US: Char7 = Char7::new_unchecked(0x1F);
+    /// Delete, `'\u{7F}'`.
+    pub const DEL: Char7 = Char7::new_unchecked(0x7F);
+
     /* conversions */
 
     /// Tries to convert a `Char8` to `Char7`.
@@ -162,7 +257,7 @@ impl Char7 {
         if is_7bit(c.to_u32()) {
             Ok(Char7::new_unchecked(c.to_u32() as u8))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c.to_u32()))
         }
     }
     /// Tries to convert a `Char16` to `Char7`.
@@ -171,7 +266,7 @@ impl Char7 {
         if is_7bit(c.to_u32()) {
             Ok(Char7::new_unchecked(c.to_u32() as u8))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c.to_u32()))
         }
     }
     /// Tries to convert a `Char24` to `Char7`.
@@ -181,7 +276,7 @@ impl Char7 {
         if is_7bit(c) {
             Ok(Char7::new_unchecked(c as u8))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c))
         }
     }
     /// Tries to convert a `Char32` to `Char8`.
@@ -190,7 +285,7 @@ impl Char7 {
         if is_7bit(c.to_u32()) {
             Ok(Char7::new_unchecked(c.to_u32() as u8))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c.to_u32()))
         }
     }
     /// Tries to convert a `char` to `Char8`.
@@ -199,14 +294,23 @@ impl Char7 {
         if is_7bit(c as u32) {
             Ok(Char7::new_unchecked(c as u32 as u8))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c as u32))
+        }
+    }
+    /// Tries to convert an ASCII byte to `Char7`.
+    #[inline]
+    pub const fn try_from_u8(byte: u8) -> Result<Char7> {
+        if is_7bit(byte as u32) {
+            Ok(Char7::new_unchecked(byte))
+        } else {
+            Err(TextosError::InvalidScalar(byte as u32))
         }
     }
     const fn from_char_unchecked(c: char) -> Char7 {
         Char7::new_unchecked(c as u32 as u8)
     }
     // useful because Option::<T>::unwrap is not yet stable as const fn
-    const fn new_unchecked(value: u8) -> Char7 {
+    pub(crate) const fn new_unchecked(value: u8) -> Char7 {
         #[cfg(not(feature = "unsafe"))]
         if let Some(c) = NonMaxU8::new(value) {
             Char7(c)
@@ -247,6 +351,11 @@ impl Char7 {
     pub const fn to_char(self) -> char {
         self.0.get() as char
     }
+    /// Converts this `Char7` to its ASCII byte.
+    #[inline]
+    pub const fn to_u8(self) -> u8 {
+        self.0.get()
+    }
     /// Converts this `Char7` to `u32`.
     #[inline]
     pub const fn to_u32(self) -> u32 {
@@ -329,7 +438,7 @@ impl Char8 {
         if byte_len(c.to_u32()) == 1 {
             Ok(Char8(c.to_u32() as u8))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c.to_u32()))
         }
     }
     /// Tries to convert a `Char24` to `Char8`.
@@ -339,7 +448,7 @@ impl Char8 {
         if byte_len(c) == 1 {
             Ok(Char8(c as u8))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c))
         }
     }
     /// Tries to convert a `Char32` to `Char8`.
@@ -348,7 +457,7 @@ impl Char8 {
         if byte_len(c.to_u32()) == 1 {
             Ok(Char8(c.to_u32() as u8))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c.to_u32()))
         }
     }
     /// Tries to convert a `char` to `Char8`.
@@ -357,7 +466,7 @@ impl Char8 {
         if byte_len(c as u32) == 1 {
             Ok(Char8(c as u32 as u8))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c as u32))
         }
     }
     const fn from_char_unchecked(c: char) -> Char8 {
@@ -502,7 +611,7 @@ impl Char16 {
         if byte_len(c) == 1 {
             Ok(Char16::new_unchecked(c as u16))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c))
         }
     }
     /// Tries to convert a `Char32` to `Char16`.
@@ -516,7 +625,7 @@ impl Char16 {
         if byte_len(c as u32) <= 2 {
             Ok(Char16::new_unchecked(c as u32 as u16))
         } else {
-            Err(TextosError::OutOfBounds)
+            Err(TextosError::InvalidScalar(c as u32))
         }
     }
     const fn from_char_unchecked(c: char) -> Char16 {
@@ -1059,6 +1168,10 @@ impl UnicodeScalar for char {
         self.to_digit(radix)
     }
     #[inline]
+    fn to_decimal_digit_unicode(self) -> Option<u32> {
+        decimal_digit(self as u32)
+    }
+    #[inline]
     fn to_ascii_uppercase(self) -> char {
         char::to_ascii_uppercase(&self)
     }
@@ -1078,6 +1191,10 @@ impl UnicodeScalar for char {
         self.is_digit(radix)
     }
     #[inline]
+    fn is_decimal_digit_unicode(self) -> bool {
+        decimal_digit(self as u32).is_some()
+    }
+    #[inline]
     fn is_control(self) -> bool {
         self.is_control()
     }
@@ -1139,6 +1256,38 @@ const fn is_7bit(code: u32) -> bool {
     code <= 0x7F
 }
 
+/// The digit zeros of the `Nd` general category, as of Unicode 15.0.
+///
+/// Each one is followed by the rest of the digits of its script, from 1 to 9.
+const DECIMAL_ZEROS: [u32; 68] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60,
+    0x16AC0, 0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0,
+    0x1E950, 0x1FBF0,
+];
+
+/// Returns the decimal digit value of the given unicode scalar code, of any script.
+const fn decimal_digit(code: u32) -> Option<u32> {
+    // binary search of the last zero that is not after the code
+    let (mut low, mut high) = (0, DECIMAL_ZEROS.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        if DECIMAL_ZEROS[mid] <= code {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    if low > 0 && code - DECIMAL_ZEROS[low - 1] < 10 {
+        Some(code - DECIMAL_ZEROS[low - 1])
+    } else {
+        None
+    }
+}
+
 /// Returns the number of bytes necessary to store the given unicode scalar code.
 #[inline]
 const fn byte_len(code: u32) -> usize {
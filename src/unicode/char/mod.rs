@@ -148,6 +148,15 @@ pub trait UnicodeScalar: Textual {
     /// Panics if given a radix larger than 36.
     fn to_digit(self, radix: u32) -> Option<u32>;
 
+    /// Converts the scalar to a decimal digit of any script.
+    ///
+    /// ‘Digit’ is defined to be any character of the `Nd` general category,
+    /// like `٣` or `３`, with its `Numeric_Value` from 0 to 9.
+    ///
+    /// # Errors
+    /// Returns None if the scalar is not a decimal digit.
+    fn to_decimal_digit_unicode(self) -> Option<u32>;
+
     /// Makes a copy of the value in its ASCII upper case equivalent.
     ///
     /// ASCII letters ‘a’ to ‘z’ are mapped to ‘A’ to ‘Z’, but non-ASCII letters
@@ -189,6 +198,11 @@ pub trait UnicodeScalar: Textual {
     /// See also [`to_digit`][Self#method.to_digit].
     fn is_digit(self, radix: u32) -> bool;
 
+    /// Checks if the unicode scalar is a decimal digit of any script.
+    ///
+    /// See also [`to_decimal_digit_unicode`][Self#method.to_decimal_digit_unicode].
+    fn is_decimal_digit_unicode(self) -> bool;
+
     /// Returns `true` if this unicode scalar has the general category for
     /// control codes.
     fn is_control(self) -> bool;
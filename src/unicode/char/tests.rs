@@ -1,5 +1,7 @@
 // textos::string::char::tests
 
+#![allow(clippy::unnecessary_fallible_conversions)]
+
 use super::*;
 use core::mem::size_of_val as size;
 
@@ -32,6 +34,10 @@ fn char_encodings() {
     assert![Char8::try_from(c2).is_ok()];
     assert![Char8::try_from(c3).is_err()];
     assert![Char8::try_from(c4).is_err()];
+    assert_eq![
+        Char8::try_from(c3),
+        Err(crate::error::TextosError::InvalidScalar(0x20AC))
+    ];
 
     assert![Char16::try_from(c1).is_ok()];
     assert![Char16::try_from(c2).is_ok()];
@@ -176,3 +182,26 @@ fn char_to_utf8_bytes() {
         );
     }
 }
+
+#[test]
+fn char_decimal_digits() {
+    for (c, digit) in [('7', 7), ('٣', 3), ('۹', 9), ('０', 0), ('𝟵', 9), ('𞥙', 9)] {
+        assert_eq![Some(digit), c.to_decimal_digit_unicode()];
+        assert_eq![Some(digit), Char32(c).to_decimal_digit_unicode()];
+    }
+    for c in ['a', '/', ':', '½', 'Ⅻ', '²', '\u{0}', char::MAX] {
+        assert![!c.is_decimal_digit_unicode()];
+    }
+    assert_eq![
+        Some(5),
+        Char8::try_from('5').unwrap().to_decimal_digit_unicode()
+    ];
+    assert_eq![
+        Some(4),
+        Char16::try_from('४').unwrap().to_decimal_digit_unicode()
+    ];
+    assert_eq![
+        None,
+        Char7::try_from('x').unwrap().to_decimal_digit_unicode()
+    ];
+}
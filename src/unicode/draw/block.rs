@@ -0,0 +1,276 @@
+// textos::unicode::draw::block
+//
+//! Block elements.
+//!
+//! Partial blocks and shades, that can be used to draw bars and charts
+//! with a precision finer than a cell.
+//!
+//! - <https://en.wikipedia.org/wiki/Block_Elements>
+//
+
+#[cfg(doc)]
+use crate::error::TextosError as Error;
+use crate::{error::TextosResult as Result, unicode::string::StaticU8String};
+use core::fmt;
+
+/// Block elements, and functions to render bars and sparklines with them.
+///
+/// All the characters in the Unicode Block Elements block are available as constants.
+pub struct BlockElements;
+
+/// # Constants
+impl BlockElements {
+    pub const UPPER_HALF: char = '▀';
+    pub const UPPER_ONE_EIGHTH: char = '▔';
+    pub const RIGHT_HALF: char = '▐';
+    pub const RIGHT_ONE_EIGHTH: char = '▕';
+
+    // LOWER
+    pub const LOWER_ONE_EIGHTH: char = '▁';
+    pub const LOWER_ONE_QUARTER: char = '▂';
+    pub const LOWER_THREE_EIGHTHS: char = '▃';
+    pub const LOWER_HALF: char = '▄';
+    pub const LOWER_FIVE_EIGHTHS: char = '▅';
+    pub const LOWER_THREE_QUARTERS: char = '▆';
+    pub const LOWER_SEVEN_EIGHTHS: char = '▇';
+    pub const FULL: char = '█';
+
+    // LEFT
+    pub const LEFT_ONE_EIGHTH: char = '▏';
+    pub const LEFT_ONE_QUARTER: char = '▎';
+    pub const LEFT_THREE_EIGHTHS: char = '▍';
+    pub const LEFT_HALF: char = '▌';
+    pub const LEFT_FIVE_EIGHTHS: char = '▋';
+    pub const LEFT_THREE_QUARTERS: char = '▊';
+    pub const LEFT_SEVEN_EIGHTHS: char = '▉';
+
+    // SHADES
+    pub const LIGHT_SHADE: char = '░';
+    pub const MEDIUM_SHADE: char = '▒';
+    pub const DARK_SHADE: char = '▓';
+
+    // QUADRANTS
+    pub const QUADRANT_LOWER_LEFT: char = '▖';
+    pub const QUADRANT_LOWER_RIGHT: char = '▗';
+    pub const QUADRANT_UPPER_LEFT: char = '▘';
+    pub const QUADRANT_UPPER_LEFT_AND_LOWER_LEFT_AND_LOWER_RIGHT: char = '▙';
+    pub const QUADRANT_UPPER_LEFT_AND_LOWER_RIGHT: char = '▚';
+    pub const QUADRANT_UPPER_LEFT_AND_UPPER_RIGHT_AND_LOWER_LEFT: char = '▛';
+    pub const QUADRANT_UPPER_LEFT_AND_UPPER_RIGHT_AND_LOWER_RIGHT: char = '▜';
+    pub const QUADRANT_UPPER_RIGHT: char = '▝';
+    pub const QUADRANT_UPPER_RIGHT_AND_LOWER_LEFT: char = '▞';
+    pub const QUADRANT_UPPER_RIGHT_AND_LOWER_LEFT_AND_LOWER_RIGHT: char = '▟';
+
+    /// The lower blocks, from empty to full, indexed by eighths.
+    pub const LOWER: [char; 9] = [
+        ' ',
+        Self::LOWER_ONE_EIGHTH,
+        Self::LOWER_ONE_QUARTER,
+        Self::LOWER_THREE_EIGHTHS,
+        Self::LOWER_HALF,
+        Self::LOWER_FIVE_EIGHTHS,
+        Self::LOWER_THREE_QUARTERS,
+        Self::LOWER_SEVEN_EIGHTHS,
+        Self::FULL,
+    ];
+
+    /// The left blocks, from empty to full, indexed by eighths.
+    pub const LEFT: [char; 9] = [
+        ' ',
+        Self::LEFT_ONE_EIGHTH,
+        Self::LEFT_ONE_QUARTER,
+        Self::LEFT_THREE_EIGHTHS,
+        Self::LEFT_HALF,
+        Self::LEFT_FIVE_EIGHTHS,
+        Self::LEFT_THREE_QUARTERS,
+        Self::LEFT_SEVEN_EIGHTHS,
+        Self::FULL,
+    ];
+
+    /// The shades, from empty to full, indexed by quarters.
+    pub const SHADES: [char; 5] = [
+        ' ',
+        Self::LIGHT_SHADE,
+        Self::MEDIUM_SHADE,
+        Self::DARK_SHADE,
+        Self::FULL,
+    ];
+}
+
+/// # Functions
+impl BlockElements {
+    /// Returns the lower block `eighths` high, saturating at the full block.
+    #[inline]
+    pub const fn lower(eighths: usize) -> char {
+        Self::LOWER[if eighths < 8 { eighths } else { 8 }]
+    }
+
+    /// Returns the left block `eighths` wide, saturating at the full block.
+    #[inline]
+    pub const fn left(eighths: usize) -> char {
+        Self::LEFT[if eighths < 8 { eighths } else { 8 }]
+    }
+
+    /// Returns the shade of the given `quarters` of density,
+    /// saturating at the full block.
+    #[inline]
+    pub const fn shade(quarters: usize) -> char {
+        Self::SHADES[if quarters < 4 { quarters } else { 4 }]
+    }
+
+    /// Writes a horizontal bar `width` cells wide, filled up to the `fraction`
+    /// with a precision of an eighth of a cell, and padded with spaces.
+    ///
+    /// The `fraction` is clamped between 0 and 1, and `NaN` is considered 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::BlockElements;
+    ///
+    /// let mut bar = String::new();
+    /// BlockElements::write_bar(&mut bar, 0.55, 4).unwrap();
+    /// assert_eq![bar, "██▎ "];
+    /// ```
+    pub fn write_bar<W: fmt::Write>(writer: &mut W, fraction: f64, width: usize) -> fmt::Result {
+        let eighths = bar_eighths(fraction, width);
+        let (full, partial) = (eighths / 8, eighths % 8);
+        for _ in 0..full {
+            writer.write_char(Self::FULL)?;
+        }
+        if partial > 0 {
+            writer.write_char(Self::left(partial))?;
+        }
+        for _ in full + usize::from(partial > 0)..width {
+            writer.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    /// Returns a horizontal bar `width` cells wide, filled up to the `fraction`.
+    ///
+    /// See [`write_bar`][Self#method.write_bar].
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][Error::NotEnoughCapacity] with the needed
+    /// capacity if the bar doesn't fit in `CAP` bytes.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::BlockElements;
+    ///
+    /// assert_eq![BlockElements::bar::<32>(1.0 / 3.0, 3).unwrap(), "█  "];
+    /// assert![BlockElements::bar::<8>(1.0, 3).is_err()];
+    /// ```
+    pub fn bar<const CAP: usize>(fraction: f64, width: usize) -> Result<StaticU8String<CAP>> {
+        StaticU8String::from_render(|mut w| Self::write_bar(&mut w, fraction, width))
+    }
+
+    /// Writes a sparkline with a lower block for each of the `values`,
+    /// scaled between the minimum and the maximum of them.
+    ///
+    /// The minimum is drawn one eighth high, so that it's always visible.
+    /// If all the values are equal they're drawn half high,
+    /// and the values that are not finite are drawn as spaces.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::BlockElements;
+    ///
+    /// let mut spark = String::new();
+    /// BlockElements::write_sparkline(&mut spark, &[1, 2, 3, 4, 5, 6, 7, 8, 4]).unwrap();
+    /// assert_eq![spark, "▁▂▃▄▅▆▇█▄"];
+    ///
+    /// spark.clear();
+    /// BlockElements::write_sparkline(&mut spark, &[0.5, f64::NAN, 0.5]).unwrap();
+    /// assert_eq![spark, "▄ ▄"];
+    /// ```
+    pub fn write_sparkline<W: fmt::Write, T: Copy + Into<f64>>(
+        writer: &mut W,
+        values: &[T],
+    ) -> fmt::Result {
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for v in values.iter().map(|v| (*v).into()).filter(|v| v.is_finite()) {
+            min = min.min(v);
+            max = max.max(v);
+        }
+        for v in values.iter().map(|v| (*v).into()) {
+            let c = if !v.is_finite() {
+                ' '
+            } else if max > min {
+                // 0..=7, rounded to the nearest
+                Self::lower(1 + ((v - min) / (max - min) * 7.0 + 0.5) as usize)
+            } else {
+                Self::LOWER_HALF
+            };
+            writer.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a sparkline with a lower block for each of the `values`.
+    ///
+    /// See [`write_sparkline`][Self#method.write_sparkline].
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][Error::NotEnoughCapacity] with the needed
+    /// capacity if the sparkline doesn't fit in `CAP` bytes.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::BlockElements;
+    ///
+    /// assert_eq![BlockElements::sparkline::<16, _>(&[3_u8, 1, 2]).unwrap(), "█▁▅"];
+    /// ```
+    pub fn sparkline<const CAP: usize, T: Copy + Into<f64>>(
+        values: &[T],
+    ) -> Result<StaticU8String<CAP>> {
+        StaticU8String::from_render(|mut w| Self::write_sparkline(&mut w, values))
+    }
+}
+
+/// Returns the number of filled eighths of a bar of `width` cells.
+fn bar_eighths(fraction: f64, width: usize) -> usize {
+    let fraction = if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    };
+    let eighths = width * 8;
+    // the cast saturates, and the fraction is never > 1
+    ((fraction * eighths as f64 + 0.5) as usize).min(eighths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TextosError as Error;
+
+    #[test]
+    fn bars() {
+        let bar = |f| BlockElements::bar::<64>(f, 2).unwrap();
+        assert_eq![bar(0.0), "  "];
+        assert_eq![bar(f64::NAN), "  "];
+        assert_eq![bar(1.0 / 16.0), "▏ "];
+        assert_eq![bar(0.5), "█ "];
+        assert_eq![bar(15.0 / 16.0), "█▉"];
+        assert_eq![bar(7.0), "██"];
+        assert_eq![BlockElements::bar::<64>(0.5, 0).unwrap(), ""];
+
+        let needed = BlockElements::bar::<4>(1.0, 2).unwrap_err();
+        assert![matches![needed, Error::NotEnoughCapacity(6)]];
+    }
+
+    #[test]
+    fn sparklines() {
+        let spark = BlockElements::sparkline::<64, i32>(&[-4, 0, 3]).unwrap();
+        assert_eq![spark, "▁▅█"];
+        assert_eq![BlockElements::sparkline::<64, f32>(&[]).unwrap(), ""];
+        assert![BlockElements::sparkline::<5, u8>(&[1, 2]).is_err()];
+    }
+}
@@ -10,7 +10,13 @@
 //! - <https://en.wikipedia.org/wiki/Box-drawing_character>
 //
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+
+/// Box-drawing characters, with a customizable thickness.
 ///
+/// All the characters in the Unicode Box Drawing block are available as constants.
 pub struct BoxDrawing {
     // Can be customized.
     thickness: BoxDrawingThickness,
@@ -76,6 +82,112 @@ impl BoxDrawing {
     pub const DOWN_LEFT_ROUND: char = '╮';
     pub const UP_LEFT_ROUND: char = '╯';
     pub const UP_RIGHT_ROUND: char = '╰';
+
+    // MIXED LIGHT & HEAVY
+    pub const DOWN_LIGHT_AND_RIGHT_HEAVY: char = '┍';
+    pub const DOWN_HEAVY_AND_RIGHT_LIGHT: char = '┎';
+    pub const DOWN_LIGHT_AND_LEFT_HEAVY: char = '┑';
+    pub const DOWN_HEAVY_AND_LEFT_LIGHT: char = '┒';
+    pub const UP_LIGHT_AND_RIGHT_HEAVY: char = '┕';
+    pub const UP_HEAVY_AND_RIGHT_LIGHT: char = '┖';
+    pub const UP_LIGHT_AND_LEFT_HEAVY: char = '┙';
+    pub const UP_HEAVY_AND_LEFT_LIGHT: char = '┚';
+    pub const VERTICAL_LIGHT_AND_RIGHT_HEAVY: char = '┝';
+    pub const UP_HEAVY_AND_RIGHT_DOWN_LIGHT: char = '┞';
+    pub const DOWN_HEAVY_AND_RIGHT_UP_LIGHT: char = '┟';
+    pub const VERTICAL_HEAVY_AND_RIGHT_LIGHT: char = '┠';
+    pub const DOWN_LIGHT_AND_RIGHT_UP_HEAVY: char = '┡';
+    pub const UP_LIGHT_AND_RIGHT_DOWN_HEAVY: char = '┢';
+    pub const VERTICAL_LIGHT_AND_LEFT_HEAVY: char = '┥';
+    pub const UP_HEAVY_AND_LEFT_DOWN_LIGHT: char = '┦';
+    pub const DOWN_HEAVY_AND_LEFT_UP_LIGHT: char = '┧';
+    pub const VERTICAL_HEAVY_AND_LEFT_LIGHT: char = '┨';
+    pub const DOWN_LIGHT_AND_LEFT_UP_HEAVY: char = '┩';
+    pub const UP_LIGHT_AND_LEFT_DOWN_HEAVY: char = '┪';
+    pub const LEFT_HEAVY_AND_RIGHT_DOWN_LIGHT: char = '┭';
+    pub const RIGHT_HEAVY_AND_LEFT_DOWN_LIGHT: char = '┮';
+    pub const DOWN_LIGHT_AND_HORIZONTAL_HEAVY: char = '┯';
+    pub const DOWN_HEAVY_AND_HORIZONTAL_LIGHT: char = '┰';
+    pub const RIGHT_LIGHT_AND_LEFT_DOWN_HEAVY: char = '┱';
+    pub const LEFT_LIGHT_AND_RIGHT_DOWN_HEAVY: char = '┲';
+    pub const LEFT_HEAVY_AND_RIGHT_UP_LIGHT: char = '┵';
+    pub const RIGHT_HEAVY_AND_LEFT_UP_LIGHT: char = '┶';
+    pub const UP_LIGHT_AND_HORIZONTAL_HEAVY: char = '┷';
+    pub const UP_HEAVY_AND_HORIZONTAL_LIGHT: char = '┸';
+    pub const RIGHT_LIGHT_AND_LEFT_UP_HEAVY: char = '┹';
+    pub const LEFT_LIGHT_AND_RIGHT_UP_HEAVY: char = '┺';
+    pub const LEFT_HEAVY_AND_RIGHT_VERTICAL_LIGHT: char = '┽';
+    pub const RIGHT_HEAVY_AND_LEFT_VERTICAL_LIGHT: char = '┾';
+    pub const VERTICAL_LIGHT_AND_HORIZONTAL_HEAVY: char = '┿';
+    pub const UP_HEAVY_AND_DOWN_HORIZONTAL_LIGHT: char = '╀';
+    pub const DOWN_HEAVY_AND_UP_HORIZONTAL_LIGHT: char = '╁';
+    pub const VERTICAL_HEAVY_AND_HORIZONTAL_LIGHT: char = '╂';
+    pub const LEFT_UP_HEAVY_AND_RIGHT_DOWN_LIGHT: char = '╃';
+    pub const RIGHT_UP_HEAVY_AND_LEFT_DOWN_LIGHT: char = '╄';
+    pub const LEFT_DOWN_HEAVY_AND_RIGHT_UP_LIGHT: char = '╅';
+    pub const RIGHT_DOWN_HEAVY_AND_LEFT_UP_LIGHT: char = '╆';
+    pub const DOWN_LIGHT_AND_UP_HORIZONTAL_HEAVY: char = '╇';
+    pub const UP_LIGHT_AND_DOWN_HORIZONTAL_HEAVY: char = '╈';
+    pub const RIGHT_LIGHT_AND_LEFT_VERTICAL_HEAVY: char = '╉';
+    pub const LEFT_LIGHT_AND_RIGHT_VERTICAL_HEAVY: char = '╊';
+
+    // MIXED SINGLE & DOUBLE
+    pub const DOWN_SINGLE_AND_RIGHT_DOUBLE: char = '╒';
+    pub const DOWN_DOUBLE_AND_RIGHT_SINGLE: char = '╓';
+    pub const DOWN_SINGLE_AND_LEFT_DOUBLE: char = '╕';
+    pub const DOWN_DOUBLE_AND_LEFT_SINGLE: char = '╖';
+    pub const UP_SINGLE_AND_RIGHT_DOUBLE: char = '╘';
+    pub const UP_DOUBLE_AND_RIGHT_SINGLE: char = '╙';
+    pub const UP_SINGLE_AND_LEFT_DOUBLE: char = '╛';
+    pub const UP_DOUBLE_AND_LEFT_SINGLE: char = '╜';
+    pub const VERTICAL_SINGLE_AND_RIGHT_DOUBLE: char = '╞';
+    pub const VERTICAL_DOUBLE_AND_RIGHT_SINGLE: char = '╟';
+    pub const VERTICAL_SINGLE_AND_LEFT_DOUBLE: char = '╡';
+    pub const VERTICAL_DOUBLE_AND_LEFT_SINGLE: char = '╢';
+    pub const DOWN_SINGLE_AND_HORIZONTAL_DOUBLE: char = '╤';
+    pub const DOWN_DOUBLE_AND_HORIZONTAL_SINGLE: char = '╥';
+    pub const UP_SINGLE_AND_HORIZONTAL_DOUBLE: char = '╧';
+    pub const UP_DOUBLE_AND_HORIZONTAL_SINGLE: char = '╨';
+    pub const VERTICAL_SINGLE_AND_HORIZONTAL_DOUBLE: char = '╪';
+    pub const VERTICAL_DOUBLE_AND_HORIZONTAL_SINGLE: char = '╫';
+
+    // DASHED
+    pub const TRIPLE_DASH_HORIZONTAL_LIGHT: char = '┄';
+    pub const TRIPLE_DASH_HORIZONTAL_HEAVY: char = '┅';
+    pub const TRIPLE_DASH_VERTICAL_LIGHT: char = '┆';
+    pub const TRIPLE_DASH_VERTICAL_HEAVY: char = '┇';
+    pub const QUADRUPLE_DASH_HORIZONTAL_LIGHT: char = '┈';
+    pub const QUADRUPLE_DASH_HORIZONTAL_HEAVY: char = '┉';
+    pub const QUADRUPLE_DASH_VERTICAL_LIGHT: char = '┊';
+    pub const QUADRUPLE_DASH_VERTICAL_HEAVY: char = '┋';
+    pub const DOUBLE_DASH_HORIZONTAL_LIGHT: char = '╌';
+    pub const DOUBLE_DASH_HORIZONTAL_HEAVY: char = '╍';
+    pub const DOUBLE_DASH_VERTICAL_LIGHT: char = '╎';
+    pub const DOUBLE_DASH_VERTICAL_HEAVY: char = '╏';
+
+    // HALF
+    pub const LEFT_LIGHT: char = '╴';
+    pub const UP_LIGHT: char = '╵';
+    pub const RIGHT_LIGHT: char = '╶';
+    pub const DOWN_LIGHT: char = '╷';
+    pub const LEFT_HEAVY: char = '╸';
+    pub const UP_HEAVY: char = '╹';
+    pub const RIGHT_HEAVY: char = '╺';
+    pub const DOWN_HEAVY: char = '╻';
+    pub const LEFT_LIGHT_AND_RIGHT_HEAVY: char = '╼';
+    pub const UP_LIGHT_AND_DOWN_HEAVY: char = '╽';
+    pub const LEFT_HEAVY_AND_RIGHT_LIGHT: char = '╾';
+    pub const UP_HEAVY_AND_DOWN_LIGHT: char = '╿';
+
+    // DIAGONAL
+    pub const DIAGONAL_UPPER_RIGHT_TO_LOWER_LEFT_LIGHT: char = '╱';
+    pub const DIAGONAL_UPPER_LEFT_TO_LOWER_RIGHT_LIGHT: char = '╲';
+    pub const DIAGONAL_CROSS_LIGHT: char = '╳';
+
+    // ASCII
+    pub const HORIZONTAL_ASCII: char = '-';
+    pub const VERTICAL_ASCII: char = '|';
+    pub const JUNCTION_ASCII: char = '+';
 }
 
 /// # Abbreviations
@@ -124,11 +236,16 @@ impl BoxDrawing {
     pub const DL3: char = Self::DOWN_LEFT_ROUND;
     pub const UR3: char = Self::UP_RIGHT_ROUND;
     pub const UL3: char = Self::UP_LEFT_ROUND;
+
+    // ASCII
+    pub const H4: char = Self::HORIZONTAL_ASCII;
+    pub const V4: char = Self::VERTICAL_ASCII;
+    pub const J4: char = Self::JUNCTION_ASCII;
 }
 
 /// # Functions
 impl BoxDrawing {
-    ///
+    /// Returns the box-drawing `piece` with the given `thickness`.
     pub const fn piece(piece: BoxDrawingPiece, thickness: BoxDrawingThickness) -> char {
         use BoxDrawingPiece::*;
         use BoxDrawingThickness::*;
@@ -177,10 +294,10 @@ impl BoxDrawing {
                     Horizontal => Self::H0,
                     Vertical => Self::V0,
                     //
-                    DownRight => Self::DR1,
-                    DownLeft => Self::DL1,
-                    UpRight => Self::UR1,
-                    UpLeft => Self::UL1,
+                    DownRight => Self::DR3,
+                    DownLeft => Self::DL3,
+                    UpRight => Self::UR3,
+                    UpLeft => Self::UL3,
                     //
                     VerticalRight => Self::VR0,
                     VerticalLeft => Self::VL0,
@@ -189,10 +306,15 @@ impl BoxDrawing {
                     VerticalHorizontal => Self::VH0,
                 }
             }
+            Ascii => match piece {
+                Horizontal => Self::H4,
+                Vertical => Self::V4,
+                _ => Self::J4,
+            },
         }
     }
 
-    ///
+    /// Returns the box-drawing `piece` with the current thickness.
     #[inline]
     pub const fn p(&self, piece: BoxDrawingPiece) -> char {
         Self::piece(piece, self.thickness)
@@ -203,7 +325,227 @@ impl BoxDrawing {
     // }
 }
 
+/// # Reverse lookup
+impl BoxDrawing {
+    /// Returns `true` if the `character` is in the Unicode Box Drawing block.
+    #[inline]
+    pub const fn is_box_drawing(character: char) -> bool {
+        matches![character, '\u{2500}'..='\u{257F}']
+    }
+
+    /// Returns the arms of the box-drawing `character`.
+    ///
+    /// Dashed lines have the arms of their solid lines,
+    /// and round corners have the arms of their light corners.
+    ///
+    /// Returns `None` for diagonals, and for characters outside the block.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::{BoxDrawing, BoxDrawingArms, BoxDrawingWeight as W};
+    ///
+    /// let arms = BoxDrawingArms::new(W::Double, W::Light, W::Double, W::None);
+    /// assert_eq![Some(arms), BoxDrawing::arms_of('╟')];
+    /// assert_eq![BoxDrawing::arms_of('─'), BoxDrawing::arms_of('┄')];
+    /// assert_eq![None, BoxDrawing::arms_of('╳')];
+    /// ```
+    pub const fn arms_of(character: char) -> Option<BoxDrawingArms> {
+        let solid = match character {
+            '┄' | '┈' | '╌' => Self::H0,
+            '┅' | '┉' | '╍' => Self::H1,
+            '┆' | '┊' | '╎' => Self::V0,
+            '┇' | '┋' | '╏' => Self::V1,
+            '╭' => Self::DR0,
+            '╮' => Self::DL0,
+            '╰' => Self::UR0,
+            '╯' => Self::UL0,
+            ' ' => return None,
+            c => c,
+        };
+        let mut bits = 0;
+        while bits < ARMS_TABLE.len() {
+            if ARMS_TABLE[bits] == solid {
+                return Some(BoxDrawingArms::from_bits(bits as u8));
+            }
+            bits += 1;
+        }
+        None
+    }
+
+    /// Returns the piece and the thickness of the box-drawing `character`.
+    ///
+    /// Returns `None` if the `character` can't be returned by
+    /// [`piece`][Self#method.piece], like mixed, dashed and half lines,
+    /// and for the ASCII characters, since they're shared by several pieces.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::{BoxDrawing, BoxDrawingPiece, BoxDrawingThickness};
+    ///
+    /// assert_eq![
+    ///     Some((BoxDrawingPiece::UpLeft, BoxDrawingThickness::Round)),
+    ///     BoxDrawing::piece_of('╯')
+    /// ];
+    /// assert_eq![None, BoxDrawing::piece_of('┢')];
+    /// ```
+    pub const fn piece_of(character: char) -> Option<(BoxDrawingPiece, BoxDrawingThickness)> {
+        use BoxDrawingThickness::*;
+        let thicknesses = [Light, Heavy, Double, Round];
+        let mut t = 0;
+        while t < thicknesses.len() {
+            let mut p = 0;
+            while p < BoxDrawingPiece::ALL.len() {
+                let piece = BoxDrawingPiece::ALL[p];
+                if Self::piece(piece, thicknesses[t]) == character {
+                    return Some((piece, thicknesses[t]));
+                }
+                p += 1;
+            }
+            t += 1;
+        }
+        None
+    }
+}
+
+/// # ASCII transcoding
+impl BoxDrawing {
+    /// Returns the ASCII fallback of the box-drawing `character`.
+    ///
+    /// Lines with only horizontal arms become `-` (or `=` if they're double),
+    /// lines with only vertical arms become `|`, any other junction becomes `+`,
+    /// and the diagonals become `/`, `\\` and `X`.
+    ///
+    /// Characters outside the Box Drawing block are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::BoxDrawing;
+    ///
+    /// assert_eq!['-', BoxDrawing::to_ascii('┄')];
+    /// assert_eq!['=', BoxDrawing::to_ascii('═')];
+    /// assert_eq!['|', BoxDrawing::to_ascii('╿')];
+    /// assert_eq!['+', BoxDrawing::to_ascii('╯')];
+    /// assert_eq!['a', BoxDrawing::to_ascii('a')];
+    /// ```
+    pub const fn to_ascii(character: char) -> char {
+        use BoxDrawingWeight as W;
+        match character {
+            '╱' => '/',
+            '╲' => '\\',
+            '╳' => 'X',
+            c if !Self::is_box_drawing(c) => c,
+            c => match Self::arms_of(c) {
+                Some(BoxDrawingArms {
+                    up: W::None,
+                    down: W::None,
+                    right,
+                    left,
+                }) => {
+                    if matches![(left, right), (W::Double, W::Double)] {
+                        '='
+                    } else {
+                        Self::H4
+                    }
+                }
+                Some(BoxDrawingArms {
+                    right: W::None,
+                    left: W::None,
+                    ..
+                }) => Self::V4,
+                _ => Self::J4,
+            },
+        }
+    }
+
+    /// Writes the `text` into the `writer`, with all its box-drawing characters
+    /// converted to their [ASCII fallbacks][Self#method.to_ascii].
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::{BoxDrawing, BoxDrawingThickness, BoxFrame};
+    ///
+    /// let frame = BoxFrame::new(BoxDrawingThickness::Round).with_content("hi");
+    /// let mut ascii = String::new();
+    /// BoxDrawing::write_ascii(&frame.to_string(), &mut ascii).unwrap();
+    /// assert_eq![ascii, "+--+\n|hi|\n+--+"];
+    /// ```
+    pub fn write_ascii<W: fmt::Write>(text: &str, writer: &mut W) -> fmt::Result {
+        fmt::Write::write_str(&mut BoxDrawingAscii::new(writer), text)
+    }
+
+    /// Returns a new string with the box-drawing characters of the `text`
+    /// converted to their [ASCII fallbacks][Self#method.to_ascii].
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::BoxDrawing;
+    ///
+    /// assert_eq![BoxDrawing::to_ascii_string("╠═╪═╣ ok"), "+=+=+ ok"];
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    pub fn to_ascii_string(text: &str) -> String {
+        text.chars().map(Self::to_ascii).collect()
+    }
+}
+
+/// A writer adapter that converts the box-drawing characters written through it
+/// to their [ASCII fallbacks][BoxDrawing#method.to_ascii].
+///
+/// It allows rendering any box-drawing output directly as ASCII.
+///
+/// # Examples
+/// ```
+/// use core::fmt::Write;
+/// use textos::unicode::draw::{BoxDrawingAscii, BoxDrawingCanvas, BoxDrawingThickness};
+///
+/// let mut c = BoxDrawingCanvas::<3, 2>::new();
+/// c.rect(0, 0, 3, 2, BoxDrawingThickness::Double);
 ///
+/// let mut ascii = String::new();
+/// c.render(&mut BoxDrawingAscii::new(&mut ascii)).unwrap();
+/// assert_eq![ascii, "+=+\n+=+"];
+/// ```
+#[derive(Debug)]
+pub struct BoxDrawingAscii<W: fmt::Write> {
+    writer: W,
+}
+
+impl<W: fmt::Write> BoxDrawingAscii<W> {
+    /// Returns a new adapter over the given `writer`.
+    #[inline]
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Returns the inner writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for BoxDrawingAscii<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // writes the runs of non box-drawing characters unchanged
+        let mut start = 0;
+        for (index, c) in s.char_indices() {
+            if BoxDrawing::is_box_drawing(c) {
+                self.writer.write_str(&s[start..index])?;
+                self.writer.write_char(BoxDrawing::to_ascii(c))?;
+                start = index + c.len_utf8();
+            }
+        }
+        self.writer.write_str(&s[start..])
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.writer.write_char(BoxDrawing::to_ascii(c))
+    }
+}
+
+/// The shape of a box-drawing piece.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
 pub enum BoxDrawingPiece {
@@ -234,6 +576,23 @@ impl BoxDrawingPiece {
     pub const HorizontalVertical: Self = Self::VerticalHorizontal;
 }
 
+impl BoxDrawingPiece {
+    /// All the pieces.
+    pub const ALL: [Self; 11] = [
+        Self::Horizontal,
+        Self::Vertical,
+        Self::DownRight,
+        Self::DownLeft,
+        Self::UpRight,
+        Self::UpLeft,
+        Self::VerticalRight,
+        Self::VerticalLeft,
+        Self::DownHorizontal,
+        Self::UpHorizontal,
+        Self::VerticalHorizontal,
+    ];
+}
+
 /// # Abbreviations
 impl BoxDrawingPiece {
     pub const H: Self = Self::Horizontal;
@@ -243,19 +602,311 @@ impl BoxDrawingPiece {
     pub const LD: Self = Self::DownLeft;
 }
 
-///
+impl BoxDrawingPiece {
+    /// Returns the arms of the `piece`, all with the given `thickness`.
+    pub const fn arms(self, thickness: BoxDrawingThickness) -> BoxDrawingArms {
+        use BoxDrawingPiece::*;
+        let (w, n) = (thickness.weight(), BoxDrawingWeight::None);
+        match self {
+            Horizontal => BoxDrawingArms::new(n, w, n, w),
+            Vertical => BoxDrawingArms::new(w, n, w, n),
+            DownRight => BoxDrawingArms::new(n, w, w, n),
+            DownLeft => BoxDrawingArms::new(n, n, w, w),
+            UpRight => BoxDrawingArms::new(w, w, n, n),
+            UpLeft => BoxDrawingArms::new(w, n, n, w),
+            VerticalRight => BoxDrawingArms::new(w, w, w, n),
+            VerticalLeft => BoxDrawingArms::new(w, n, w, w),
+            DownHorizontal => BoxDrawingArms::new(n, w, w, w),
+            UpHorizontal => BoxDrawingArms::new(w, w, n, w),
+            VerticalHorizontal => BoxDrawingArms::new(w, w, w, w),
+        }
+    }
+}
+
+/// The thickness of a box-drawing piece.
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BoxDrawingThickness {
-    ///
+    /// Light lines.
     Light = 0,
 
-    ///
+    /// Heavy lines.
     Heavy = 1,
 
-    ///
+    /// Double lines.
     Double = 2,
 
     /// Round corners, light walls.
     Round = 3,
+
+    /// ASCII characters: `-`, `|` and `+` for every junction.
+    ///
+    /// The types that merge the arms of the lines, like
+    /// [`BoxDrawingCanvas`][super::BoxDrawingCanvas], draw it with `Light` weight.
+    /// Their output can be converted with [`BoxDrawingAscii`].
+    Ascii = 4,
+}
+
+impl BoxDrawingThickness {
+    /// Returns the weight of the lines.
+    ///
+    /// `Round` and `Ascii` have `Light` weight.
+    pub const fn weight(self) -> BoxDrawingWeight {
+        match self {
+            BoxDrawingThickness::Light
+            | BoxDrawingThickness::Round
+            | BoxDrawingThickness::Ascii => BoxDrawingWeight::Light,
+            BoxDrawingThickness::Heavy => BoxDrawingWeight::Heavy,
+            BoxDrawingThickness::Double => BoxDrawingWeight::Double,
+        }
+    }
+}
+
+/// The weight of a single arm of a box-drawing piece.
+///
+/// The weights are ordered, so that the heaviest wins when merging arms.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BoxDrawingWeight {
+    /// No arm.
+    #[default]
+    None = 0,
+
+    /// A light arm.
+    Light = 1,
+
+    /// A heavy arm.
+    Heavy = 2,
+
+    /// A double arm.
+    Double = 3,
+}
+
+impl BoxDrawingWeight {
+    /// Returns the heaviest of both weights.
+    #[inline]
+    pub const fn max(self, other: Self) -> Self {
+        if other as u8 > self as u8 {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    const fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0 => BoxDrawingWeight::None,
+            1 => BoxDrawingWeight::Light,
+            2 => BoxDrawingWeight::Heavy,
+            _ => BoxDrawingWeight::Double,
+        }
+    }
+}
+
+/// The weights of the four arms of a box-drawing piece,
+/// going from the center of the cell to each of its sides.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::draw::{BoxDrawingArms as Arms, BoxDrawingWeight as W};
+///
+/// assert_eq![Some('┼'), Arms::new(W::Light, W::Light, W::Light, W::Light).to_char()];
+/// assert_eq![Some('┢'), Arms::new(W::Light, W::Heavy, W::Heavy, W::None).to_char()];
+/// assert_eq![Some('╟'), Arms::new(W::Double, W::Light, W::Double, W::None).to_char()];
+///
+/// // there's no character for mixing heavy and double arms
+/// let arms = Arms::new(W::Heavy, W::Double, W::None, W::None);
+/// assert_eq![None, arms.to_char()];
+/// assert_eq!['╚', arms.to_char_lossy()];
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BoxDrawingArms {
+    /// The weight of the arm going up.
+    pub up: BoxDrawingWeight,
+    /// The weight of the arm going right.
+    pub right: BoxDrawingWeight,
+    /// The weight of the arm going down.
+    pub down: BoxDrawingWeight,
+    /// The weight of the arm going left.
+    pub left: BoxDrawingWeight,
+}
+
+impl BoxDrawingArms {
+    /// No arms.
+    pub const NONE: Self = Self::new(
+        BoxDrawingWeight::None,
+        BoxDrawingWeight::None,
+        BoxDrawingWeight::None,
+        BoxDrawingWeight::None,
+    );
+
+    /// Returns new arms with the given weights, in clockwise order.
+    #[inline]
+    pub const fn new(
+        up: BoxDrawingWeight,
+        right: BoxDrawingWeight,
+        down: BoxDrawingWeight,
+        left: BoxDrawingWeight,
+    ) -> Self {
+        Self {
+            up,
+            right,
+            down,
+            left,
+        }
+    }
+
+    /// Returns `true` if there are no arms.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.to_bits() == 0
+    }
+
+    /// Returns the arms of both, keeping the heaviest weight in each direction.
+    #[inline]
+    pub const fn merge(self, other: Self) -> Self {
+        Self {
+            up: self.up.max(other.up),
+            right: self.right.max(other.right),
+            down: self.down.max(other.down),
+            left: self.left.max(other.left),
+        }
+    }
+
+    /// Returns the box-drawing character with exactly these arms.
+    ///
+    /// Returns `None` if there are no arms, or if there's no such character,
+    /// which happens when mixing double arms with heavy arms,
+    /// and with some combinations of double and light arms.
+    #[inline]
+    pub const fn to_char(self) -> Option<char> {
+        match ARMS_TABLE[self.to_bits() as usize] {
+            ' ' => None,
+            c => Some(c),
+        }
+    }
+
+    /// Returns the box-drawing character that best matches these arms.
+    ///
+    /// If there's no exact match, heavy arms are first made double,
+    /// and if there's still no match, double arms are made heavy.
+    ///
+    /// Returns a space if there are no arms.
+    pub const fn to_char_lossy(self) -> char {
+        let bits = self.to_bits();
+        let exact = ARMS_TABLE[bits as usize];
+        if exact != ' ' || bits == 0 {
+            return exact;
+        }
+        let doubled = ARMS_TABLE[Self::replace_bits(bits, 2, 3) as usize];
+        if doubled != ' ' {
+            return doubled;
+        }
+        ARMS_TABLE[Self::replace_bits(bits, 3, 2) as usize]
+    }
+
+    /// Returns the arms packed in a byte, 2 bits per arm,
+    /// from the least significant bits: up, right, down, left.
+    #[inline]
+    pub const fn to_bits(self) -> u8 {
+        self.up as u8 | (self.right as u8) << 2 | (self.down as u8) << 4 | (self.left as u8) << 6
+    }
+
+    /// Returns the arms unpacked from a byte, 2 bits per arm,
+    /// from the least significant bits: up, right, down, left.
+    #[inline]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self::new(
+            BoxDrawingWeight::from_bits(bits),
+            BoxDrawingWeight::from_bits(bits >> 2),
+            BoxDrawingWeight::from_bits(bits >> 4),
+            BoxDrawingWeight::from_bits(bits >> 6),
+        )
+    }
+
+    // Replaces the arms of weight `from` with weight `to`.
+    const fn replace_bits(bits: u8, from: u8, to: u8) -> u8 {
+        let (mut i, mut result) = (0, 0);
+        while i < 8 {
+            let arm = (bits >> i) & 0b11;
+            result |= if arm == from { to } else { arm } << i;
+            i += 2;
+        }
+        result
+    }
+}
+
+// The box-drawing characters indexed by their packed arms. Spaces are missing.
+#[rustfmt::skip]
+const ARMS_TABLE: [char; 256] = [
+    ' ', '╵', '╹', ' ', '╶', '└', '┖', '╙', '╺', '┕', '┗', ' ', ' ', '╘', ' ', '╚', // 0x00
+    '╷', '│', '╿', ' ', '┌', '├', '┞', ' ', '┍', '┝', '┡', ' ', '╒', '╞', ' ', ' ', // 0x10
+    '╻', '╽', '┃', ' ', '┎', '┟', '┠', ' ', '┏', '┢', '┣', ' ', ' ', ' ', ' ', ' ', // 0x20
+    ' ', ' ', ' ', '║', '╓', ' ', ' ', '╟', ' ', ' ', ' ', ' ', '╔', ' ', ' ', '╠', // 0x30
+    '╴', '┘', '┚', '╜', '─', '┴', '┸', '╨', '╼', '┶', '┺', ' ', ' ', ' ', ' ', ' ', // 0x40
+    '┐', '┤', '┦', ' ', '┬', '┼', '╀', ' ', '┮', '┾', '╄', ' ', ' ', ' ', ' ', ' ', // 0x50
+    '┒', '┧', '┨', ' ', '┰', '╁', '╂', ' ', '┲', '╆', '╊', ' ', ' ', ' ', ' ', ' ', // 0x60
+    '╖', ' ', ' ', '╢', '╥', ' ', ' ', '╫', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', // 0x70
+    '╸', '┙', '┛', ' ', '╾', '┵', '┹', ' ', '━', '┷', '┻', ' ', ' ', ' ', ' ', ' ', // 0x80
+    '┑', '┥', '┩', ' ', '┭', '┽', '╃', ' ', '┯', '┿', '╇', ' ', ' ', ' ', ' ', ' ', // 0x90
+    '┓', '┪', '┫', ' ', '┱', '╅', '╉', ' ', '┳', '╈', '╋', ' ', ' ', ' ', ' ', ' ', // 0xa0
+    ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', // 0xb0
+    ' ', '╛', ' ', '╝', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '═', '╧', ' ', '╩', // 0xc0
+    '╕', '╡', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '╤', '╪', ' ', ' ', // 0xd0
+    ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', // 0xe0
+    '╗', ' ', ' ', '╣', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '╦', ' ', ' ', '╬', // 0xf0
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_corners() {
+        use BoxDrawingPiece::*;
+        let round = |p| BoxDrawing::piece(p, BoxDrawingThickness::Round);
+        assert_eq![
+            ['╭', '╮', '╰', '╯'],
+            [DownRight, DownLeft, UpRight, UpLeft].map(round)
+        ];
+        assert_eq!['─', round(Horizontal)];
+        assert_eq!['├', round(VerticalRight)];
+    }
+
+    #[test]
+    fn pieces_match_arms() {
+        use BoxDrawingPiece as Piece;
+        use BoxDrawingThickness::*;
+        use BoxDrawingWeight::{Double as D, Heavy as H, Light as L, None as N};
+
+        // (piece, thickness, [up, right, down, left])
+        for (p, t, [u, r, d, l]) in [
+            (Piece::H, Light, [N, L, N, L]),
+            (Piece::V, Heavy, [H, N, H, N]),
+            (Piece::DownRight, Heavy, [N, H, H, N]),
+            (Piece::UpLeft, Double, [D, N, N, D]),
+            (Piece::VerticalHorizontal, Light, [L, L, L, L]),
+        ] {
+            assert_eq![BoxDrawingArms::new(u, r, d, l), p.arms(t)];
+            assert_eq![Some((p, t)), BoxDrawing::piece_of(BoxDrawing::piece(p, t))];
+        }
+        // (character, [up, right, down, left])
+        for (c, [u, r, d, l]) in [
+            ('─', [N, L, N, L]),
+            ('┃', [H, N, H, N]),
+            ('┏', [N, H, H, N]),
+            ('╝', [D, N, N, D]),
+            ('┼', [L, L, L, L]),
+            ('┢', [L, H, H, N]),
+            ('╟', [D, L, D, N]),
+            ('╪', [L, D, L, D]),
+            ('╴', [N, N, N, L]),
+            ('╻', [N, N, H, N]),
+        ] {
+            let arms = BoxDrawingArms::new(u, r, d, l);
+            assert_eq![Some(arms), BoxDrawing::arms_of(c)];
+            assert_eq![Some(c), arms.to_char()];
+        }
+    }
 }
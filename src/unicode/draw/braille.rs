@@ -0,0 +1,382 @@
+// textos::unicode::draw::braille
+//
+//! Braille patterns.
+//!
+//! Each braille character has a matrix of 2×4 dots, which allows drawing
+//! with a resolution higher than the cells.
+//!
+//! - <https://en.wikipedia.org/wiki/Braille_Patterns>
+//
+
+use crate::{
+    error::{TextosError as Error, TextosResult as Result},
+    unicode::string::StaticU8String,
+};
+use core::fmt;
+
+/// A grid of `W`×`H` cells of braille patterns, where to draw dots.
+///
+/// It has `2 * W` dots horizontally and `4 * H` dots vertically,
+/// with the origin in the top-left corner.
+///
+/// Dots that go out of bounds are clipped.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::draw::BrailleCanvas;
+///
+/// let mut c = BrailleCanvas::<4, 2>::new();
+/// c.line(0, 7, 7, 0);
+/// c.set(0, 0);
+///
+/// assert_eq![c.to_string(), "⠁⠀⡠⠊\n⡠⠊⠀⠀"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BrailleCanvas<const W: usize, const H: usize> {
+    cells: [[u8; W]; H],
+}
+
+impl<const W: usize, const H: usize> BrailleCanvas<W, H> {
+    /// The first braille pattern, with no dots.
+    pub const BLANK: char = '\u{2800}';
+
+    /// Returns a new empty canvas.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { cells: [[0; W]; H] }
+    }
+
+    /// Returns the width in dots.
+    #[inline]
+    pub const fn width() -> usize {
+        W * 2
+    }
+
+    /// Returns the height in dots.
+    #[inline]
+    pub const fn height() -> usize {
+        H * 4
+    }
+
+    /// Clears all the dots.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cells = [[0; W]; H];
+    }
+
+    //
+
+    /// Returns the bit of the dot at `x`, `y` in its cell.
+    const fn dot_bit(x: usize, y: usize) -> u8 {
+        // dots 1-2-3-7 on the left column and 4-5-6-8 on the right one
+        const BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+        BITS[y % 4][x % 2]
+    }
+
+    /// Returns `true` if the dot at `x`, `y` is set, or `false` if it's out of bounds.
+    #[inline]
+    pub const fn get(&self, x: usize, y: usize) -> bool {
+        if x < Self::width() && y < Self::height() {
+            self.cells[y / 4][x / 2] & Self::dot_bit(x, y) != 0
+        } else {
+            false
+        }
+    }
+
+    /// Sets the dot at `x`, `y`, if it's in bounds.
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize) {
+        if let Some(cell) = self.cells.get_mut(y / 4).and_then(|row| row.get_mut(x / 2)) {
+            *cell |= Self::dot_bit(x, y);
+        }
+    }
+
+    /// Unsets the dot at `x`, `y`, if it's in bounds.
+    #[inline]
+    pub fn unset(&mut self, x: usize, y: usize) {
+        if let Some(cell) = self.cells.get_mut(y / 4).and_then(|row| row.get_mut(x / 2)) {
+            *cell &= !Self::dot_bit(x, y);
+        }
+    }
+
+    /// Toggles the dot at `x`, `y`, if it's in bounds.
+    #[inline]
+    pub fn toggle(&mut self, x: usize, y: usize) {
+        if let Some(cell) = self.cells.get_mut(y / 4).and_then(|row| row.get_mut(x / 2)) {
+            *cell ^= Self::dot_bit(x, y);
+        }
+    }
+
+    /// Sets the dot at the wide signed `x`, `y`, if it's in bounds.
+    #[inline]
+    fn set_wide(&mut self, x: i128, y: i128) {
+        if let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) {
+            self.set(x, y);
+        }
+    }
+
+    /// Returns the braille character of the cell at `x`, `y`,
+    /// or `None` if out of bounds.
+    #[inline]
+    pub const fn cell(&self, x: usize, y: usize) -> Option<char> {
+        if x < W && y < H {
+            Some(braille(self.cells[y][x]))
+        } else {
+            None
+        }
+    }
+
+    //
+
+    /// Draws a line of dots from `x0`, `y0` to `x1`, `y1`, both included.
+    ///
+    /// The coordinates can be negative or out of bounds,
+    /// and only the visible dots are drawn.
+    ///
+    /// The line is clipped to the canvas first, so drawing it takes
+    /// as many steps as the canvas is wide or high, at most.
+    pub fn line(&mut self, x0: isize, y0: isize, x1: isize, y1: isize) {
+        let (x0, y0, x1, y1) = (x0 as i128, y0 as i128, x1 as i128, y1 as i128);
+        // one dot per step along the longest axis
+        if x0.abs_diff(x1) >= y0.abs_diff(y1) {
+            for (x, y) in line_steps((x0, y0), (x1, y1), Self::width()) {
+                self.set_wide(x, y);
+            }
+        } else {
+            for (y, x) in line_steps((y0, x0), (y1, x1), Self::height()) {
+                self.set_wide(x, y);
+            }
+        }
+    }
+
+    /// Draws a circle of dots centered at `cx`, `cy`, with the given `radius`.
+    ///
+    /// The coordinates can be negative or out of bounds,
+    /// and only the visible dots are drawn.
+    ///
+    /// The circle is clipped to the canvas first, so drawing it takes
+    /// as many steps as the canvas is wide and high, at most.
+    /// A `radius` greater than `isize::MAX` draws nothing.
+    pub fn circle(&mut self, cx: isize, cy: isize, radius: usize) {
+        let Ok(radius) = isize::try_from(radius) else {
+            return;
+        };
+        let (cx, cy, radius) = (cx as i128, cy as i128, radius as u128);
+        // one dot per column in the octants around the vertical axis
+        for x in 0..Self::width() as i128 {
+            if let Some(offset) = octant_offset(radius, x.abs_diff(cx)) {
+                self.set_wide(x, cy - offset);
+                self.set_wide(x, cy + offset);
+            }
+        }
+        // and one dot per row in the octants around the horizontal axis
+        for y in 0..Self::height() as i128 {
+            if let Some(offset) = octant_offset(radius, y.abs_diff(cy)) {
+                self.set_wide(cx - offset, y);
+                self.set_wide(cx + offset, y);
+            }
+        }
+    }
+
+    //
+
+    /// Writes the row of cells `y` into the given `writer`, if it's in bounds.
+    pub fn render_row<Wr: fmt::Write>(&self, y: usize, writer: &mut Wr) -> fmt::Result {
+        if let Some(row) = self.cells.get(y) {
+            for bits in row {
+                writer.write_char(braille(*bits))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the row of cells `y` as a static string.
+    ///
+    /// # Errors
+    /// Returns [`OutOfBounds`][Error::OutOfBounds] if `y` >= `H`,
+    /// or [`NotEnoughCapacity`][Error::NotEnoughCapacity] if the `W` characters,
+    /// of 3 bytes each, don't fit in `CAP` bytes.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::draw::BrailleCanvas;
+    ///
+    /// let mut c = BrailleCanvas::<2, 1>::new();
+    /// c.line(0, 3, 3, 3);
+    /// assert_eq![c.row_string::<6>(0).unwrap(), "⣀⣀"];
+    /// assert![c.row_string::<5>(0).is_err()];
+    /// assert![c.row_string::<6>(1).is_err()];
+    /// ```
+    pub fn row_string<const CAP: usize>(&self, y: usize) -> Result<StaticU8String<CAP>> {
+        if y >= H {
+            return Err(Error::OutOfBounds);
+        }
+        let mut string = StaticU8String::new();
+        self.render_row(y, &mut string)
+            .map_err(|_| Error::NotEnoughCapacity(W * 3))?;
+        Ok(string)
+    }
+
+    /// Renders the canvas into the given `writer`.
+    ///
+    /// The rows are separated by line feeds, with no line ending after the last.
+    pub fn render<Wr: fmt::Write>(&self, writer: &mut Wr) -> fmt::Result {
+        for y in 0..H {
+            if y > 0 {
+                writer.write_char('\n')?;
+            }
+            self.render_row(y, writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const W: usize, const H: usize> Default for BrailleCanvas<W, H> {
+    /// Returns an empty canvas.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, const H: usize> fmt::Display for BrailleCanvas<W, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f)
+    }
+}
+
+/// Returns the braille pattern with the given dot `bits`.
+const fn braille(bits: u8) -> char {
+    // all the 256 code points from U+2800 are braille patterns
+    match char::from_u32(0x2800 + bits as u32) {
+        Some(c) => c,
+        None => unreachable!(),
+    }
+}
+
+/// Returns the points of the line from `a0`, `b0` to `a1`, `b1` whose `a`
+/// coordinate is in `0..len`, where `a` is the axis with the longest distance.
+///
+/// Each `b` is rounded to the nearest, computed without overflowing.
+fn line_steps(
+    (a0, b0): (i128, i128),
+    (a1, b1): (i128, i128),
+    len: usize,
+) -> impl Iterator<Item = (i128, i128)> {
+    let ((a0, b0), (a1, b1)) = if a0 <= a1 {
+        ((a0, b0), (a1, b1))
+    } else {
+        ((a1, b1), (a0, b0))
+    };
+    let (da, db) = (a0.abs_diff(a1), b0.abs_diff(b1));
+    let (start, end) = (a0.max(0), a1.min(len as i128 - 1));
+    (start..=end).map(move |a| {
+        if da == 0 {
+            return (a, b0);
+        }
+        // both factors are < 2^64, so their product fits
+        let product = a.abs_diff(a0) * db;
+        let (quotient, remainder) = (product / da, product % da);
+        let offset = (quotient + u128::from(2 * remainder >= da)) as i128;
+        (a, if b1 >= b0 { b0 + offset } else { b0 - offset })
+    })
+}
+
+/// Returns the offset along the other axis of the circle point of the given
+/// `radius` at `distance` from the center, if that point is in an octant
+/// where `distance` is the shortest of both.
+///
+/// It picks the same points as the midpoint circle algorithm, the `offset`
+/// being the largest one whose midpoint `offset - ½` is inside the circle.
+///
+/// The `radius` must be <= `isize::MAX`, so that its square fits.
+fn octant_offset(radius: u128, distance: u128) -> Option<i128> {
+    if radius == 0 {
+        return (distance == 0).then_some(0);
+    } else if distance >= radius {
+        return None;
+    }
+    // the largest offset with (offset - ½)² < radius² - distance²
+    let area = radius * radius - distance * distance;
+    let offset = (1 + isqrt(4 * area - 3)) / 2;
+    (offset >= distance).then_some(offset as i128)
+}
+
+/// Returns the integer square root of `n`.
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method, starting from a power of two above the root
+    let mut x = 1 << ((128 - n.leading_zeros() + 1) / 2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn dots() {
+        let mut c = BrailleCanvas::<1, 1>::new();
+        for y in 0..4 {
+            c.set(0, y);
+            c.set(1, y);
+        }
+        assert_eq![Some('⣿'), c.cell(0, 0)];
+        c.unset(1, 3);
+        c.toggle(0, 0);
+        c.toggle(9, 9);
+        assert![!c.get(0, 0) && !c.get(1, 3) && c.get(1, 0)];
+        assert_eq![Some('⡾'), c.cell(0, 0)];
+        assert_eq![None, c.cell(1, 0)];
+    }
+
+    #[test]
+    fn shapes() {
+        let mut c = BrailleCanvas::<3, 2>::new();
+        c.circle(2, 3, 2);
+        c.line(-4, 7, 20, 7);
+        assert_eq![c.to_string(), "⡔⠒⡄\n⣑⣒⣁"];
+        assert_eq![6, BrailleCanvas::<3, 2>::width()];
+
+        // far away endpoints are clipped, without overflowing
+        let mut far = BrailleCanvas::<3, 2>::new();
+        far.line(isize::MIN, 7, isize::MAX, 7);
+        far.line(0, isize::MAX, 0, isize::MIN);
+        far.line(isize::MIN, isize::MIN, isize::MAX, isize::MAX);
+        assert_eq![far.to_string(), "⡗⢄⠀\n⣇⣀⣑"];
+        c.clear();
+        c.line(20, 7, -4, 7);
+        assert_eq![c.to_string(), "⠀⠀⠀\n⣀⣀⣀"];
+    }
+
+    #[test]
+    fn far_circles() {
+        // far away centers and huge radii are clipped, without overflowing
+        let mut c = BrailleCanvas::<2, 2>::new();
+        c.circle(isize::MAX, 0, 1);
+        c.circle(isize::MIN, isize::MIN, isize::MAX as usize);
+        c.circle(0, 0, usize::MAX);
+        assert_eq![c, BrailleCanvas::new()];
+
+        // a circle much bigger than the canvas crosses it
+        c.circle(-(1 << 40), 3, 1 << 40);
+        c.circle(1, isize::MAX, isize::MAX as usize);
+        assert_eq![c.to_string(), "⡏⠉\n⡇⠀"];
+        c.clear();
+        c.circle(1, 1, 0);
+        assert_eq![c.to_string(), "⠐⠀\n⠀⠀"];
+    }
+}
@@ -0,0 +1,223 @@
+// textos::unicode::draw::canvas
+//
+//! A grid of box-drawing cells.
+//
+
+use super::box_drawing::{BoxDrawingArms, BoxDrawingThickness};
+use core::fmt;
+
+/// A grid of `W`×`H` cells where to draw box-drawing lines.
+///
+/// The arms of the lines that meet in a cell are merged, keeping the heaviest
+/// weight in each direction, and each cell is rendered as the junction that
+/// best matches its arms.
+///
+/// Lines that go out of bounds are clipped.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::draw::{BoxDrawingCanvas, BoxDrawingThickness::*};
+///
+/// let mut c = BoxDrawingCanvas::<5, 3>::new();
+/// c.rect(0, 0, 5, 3, Light);
+/// c.vline(2, 0, 3, Heavy);
+/// c.hline(0, 1, 5, Double);
+///
+/// // heavy and double lines can't join, so the crossing is all double
+/// assert_eq![c.to_string(), "\
+/// ┌─┰─┐
+/// ╞═╬═╡
+/// └─┸─┘"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoxDrawingCanvas<const W: usize, const H: usize> {
+    cells: [[BoxDrawingArms; W]; H],
+}
+
+impl<const W: usize, const H: usize> BoxDrawingCanvas<W, H> {
+    /// Returns a new empty canvas.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            cells: [[BoxDrawingArms::NONE; W]; H],
+        }
+    }
+
+    /// Returns the width in cells.
+    #[inline]
+    pub const fn width() -> usize {
+        W
+    }
+
+    /// Returns the height in cells.
+    #[inline]
+    pub const fn height() -> usize {
+        H
+    }
+
+    /// Empties all the cells.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cells = [[BoxDrawingArms::NONE; W]; H];
+    }
+
+    //
+
+    /// Returns the arms of the cell at `x`, `y`, or `None` if out of bounds.
+    #[inline]
+    pub const fn get(&self, x: usize, y: usize) -> Option<BoxDrawingArms> {
+        if x < W && y < H {
+            Some(self.cells[y][x])
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the arms of the cell at `x`, `y`, if it's in bounds.
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, arms: BoxDrawingArms) {
+        if let Some(cell) = self.cells.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *cell = arms;
+        }
+    }
+
+    /// Merges the `arms` into the cell at `x`, `y`, if it's in bounds.
+    #[inline]
+    pub fn add(&mut self, x: usize, y: usize, arms: BoxDrawingArms) {
+        if let Some(cell) = self.cells.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *cell = cell.merge(arms);
+        }
+    }
+
+    /// Returns the character of the cell at `x`, `y`, or `None` if out of bounds.
+    ///
+    /// Empty cells are spaces.
+    #[inline]
+    pub const fn char_at(&self, x: usize, y: usize) -> Option<char> {
+        match self.get(x, y) {
+            Some(arms) => Some(arms.to_char_lossy()),
+            None => None,
+        }
+    }
+
+    //
+
+    /// Draws a horizontal line of `len` cells, starting at `x`, `y`
+    /// and going right.
+    ///
+    /// The line goes from the center of its first cell to the center of its last.
+    pub fn hline(&mut self, x: usize, y: usize, len: usize, thickness: BoxDrawingThickness) {
+        let w = thickness.weight();
+        let right = BoxDrawingArms {
+            right: w,
+            ..BoxDrawingArms::NONE
+        };
+        let left = BoxDrawingArms {
+            left: w,
+            ..BoxDrawingArms::NONE
+        };
+        // the cells past the canvas are not drawn
+        let end = x.saturating_add(len).saturating_sub(1).min(W);
+        for i in x..end {
+            self.add(i, y, right);
+            self.add(i + 1, y, left);
+        }
+    }
+
+    /// Draws a vertical line of `len` cells, starting at `x`, `y`
+    /// and going down.
+    ///
+    /// The line goes from the center of its first cell to the center of its last.
+    pub fn vline(&mut self, x: usize, y: usize, len: usize, thickness: BoxDrawingThickness) {
+        let w = thickness.weight();
+        let down = BoxDrawingArms {
+            down: w,
+            ..BoxDrawingArms::NONE
+        };
+        let up = BoxDrawingArms {
+            up: w,
+            ..BoxDrawingArms::NONE
+        };
+        let end = y.saturating_add(len).saturating_sub(1).min(H);
+        for i in y..end {
+            self.add(x, i, down);
+            self.add(x, i + 1, up);
+        }
+    }
+
+    /// Draws a rectangle of `width`×`height` cells, with its top-left corner
+    /// at `x`, `y`.
+    pub fn rect(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+        thickness: BoxDrawingThickness,
+    ) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        self.hline(x, y, width, thickness);
+        self.hline(x, y.saturating_add(height - 1), width, thickness);
+        self.vline(x, y, height, thickness);
+        self.vline(x.saturating_add(width - 1), y, height, thickness);
+    }
+
+    //
+
+    /// Renders the canvas into the given `writer`.
+    ///
+    /// The rows are separated by line feeds, with no line ending after the last.
+    pub fn render<Wr: fmt::Write>(&self, writer: &mut Wr) -> fmt::Result {
+        for (y, row) in self.cells.iter().enumerate() {
+            if y > 0 {
+                writer.write_char('\n')?;
+            }
+            for arms in row {
+                writer.write_char(arms.to_char_lossy())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const W: usize, const H: usize> Default for BoxDrawingCanvas<W, H> {
+    /// Returns an empty canvas.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, const H: usize> fmt::Display for BoxDrawingCanvas<W, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn junctions() {
+        let mut c = BoxDrawingCanvas::<4, 4>::new();
+        c.rect(0, 0, 3, 3, BoxDrawingThickness::Light);
+        c.hline(0, 1, 9, BoxDrawingThickness::Heavy);
+        c.vline(1, 0, 2, BoxDrawingThickness::Light);
+        assert_eq![c.to_string(), "┌┬┐ \n┝┷┿━\n└─┘ \n    "];
+        assert_eq![Some(' '), c.char_at(3, 3)];
+        assert_eq![None, c.char_at(4, 0)];
+
+        // the lines past the canvas are clipped
+        let mut c = BoxDrawingCanvas::<3, 2>::new();
+        c.hline(1, 0, usize::MAX, BoxDrawingThickness::Light);
+        c.hline(0, 1, usize::MAX, BoxDrawingThickness::Heavy);
+        c.vline(usize::MAX, 0, usize::MAX, BoxDrawingThickness::Light);
+        c.rect(2, 1, usize::MAX, usize::MAX, BoxDrawingThickness::Light);
+        assert_eq![c.to_string(), " ╶─\n╺━┯"];
+    }
+}
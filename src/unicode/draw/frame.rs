@@ -0,0 +1,223 @@
+// textos::unicode::draw::frame
+//
+//! Framed boxes.
+//
+
+use super::box_drawing::{BoxDrawing, BoxDrawingPiece as Piece, BoxDrawingThickness};
+use crate::{
+    fmt::Alignment,
+    unicode::width::{str_truncate_width, str_width},
+};
+use core::fmt;
+
+/// A box with a box-drawing frame, an optional title and multi-line content.
+///
+/// The sizes and the padding are measured in terminal columns and rows,
+/// and the text is truncated without splitting extended grapheme clusters.
+///
+/// It can be rendered into any [`fmt::Write`], or into a `String` by
+/// using [`Display`][fmt::Display].
+///
+/// # Examples
+/// ```
+/// use textos::{fmt::Alignment, unicode::draw::{BoxDrawingThickness, BoxFrame}};
+///
+/// let frame = BoxFrame::new(BoxDrawingThickness::Round)
+///     .with_title("漢字")
+///     .with_content("hello\nworld!")
+///     .with_padding(1)
+///     .with_align(Alignment::Center);
+///
+/// assert_eq![frame.to_string(), "\
+/// ╭─ 漢字 ─╮
+/// │ hello  │
+/// │ world! │
+/// ╰────────╯"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoxFrame<'a> {
+    thickness: BoxDrawingThickness,
+    width: Option<usize>,
+    height: Option<usize>,
+    padding: usize,
+    align: Alignment,
+    title: Option<&'a str>,
+    title_align: Alignment,
+    content: &'a str,
+}
+
+impl<'a> BoxFrame<'a> {
+    /// Returns a new empty frame with the given `thickness`,
+    /// that fits its content.
+    #[inline]
+    pub const fn new(thickness: BoxDrawingThickness) -> Self {
+        Self {
+            thickness,
+            width: None,
+            height: None,
+            padding: 0,
+            align: Alignment::Left,
+            title: None,
+            title_align: Alignment::Center,
+            content: "",
+        }
+    }
+
+    /// Sets the total `width`, including the borders.
+    ///
+    /// It can't be less than 2.
+    #[inline]
+    #[must_use]
+    pub const fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the total `height`, including the borders.
+    ///
+    /// It can't be less than 2.
+    #[inline]
+    #[must_use]
+    pub const fn with_height(mut self, height: usize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the `padding` on each side of the content lines.
+    #[inline]
+    #[must_use]
+    pub const fn with_padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the alignment of the content lines.
+    #[inline]
+    #[must_use]
+    pub const fn with_align(mut self, align: Alignment) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets the `title`, drawn over the top border.
+    #[inline]
+    #[must_use]
+    pub const fn with_title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the alignment of the title. It's centered by default.
+    #[inline]
+    #[must_use]
+    pub const fn with_title_align(mut self, align: Alignment) -> Self {
+        self.title_align = align;
+        self
+    }
+
+    /// Sets the multi-line `content`.
+    #[inline]
+    #[must_use]
+    pub const fn with_content(mut self, content: &'a str) -> Self {
+        self.content = content;
+        self
+    }
+
+    //
+
+    /// Returns the total width, including the borders.
+    pub fn width(&self) -> usize {
+        self.width
+            .unwrap_or_else(|| {
+                let content = self.content.lines().map(str_width).max().unwrap_or(0);
+                let title = self.title.map_or(0, |t| str_width(t) + 4);
+                title.max(content + 2 * self.padding + 2)
+            })
+            .max(2)
+    }
+
+    /// Returns the total height, including the borders.
+    pub fn height(&self) -> usize {
+        self.height
+            .unwrap_or_else(|| self.content.lines().count() + 2)
+            .max(2)
+    }
+
+    /// Renders the frame into the given `writer`.
+    ///
+    /// There's no line ending after the bottom border.
+    pub fn render<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        let p = |piece| BoxDrawing::piece(piece, self.thickness);
+        let inner = self.width() - 2;
+
+        // top border
+        writer.write_char(p(Piece::DownRight))?;
+        match self.title {
+            Some(title) if inner >= 2 => {
+                let (title, title_width) = str_truncate_width(title, inner - 2);
+                let (left, right) = self.title_align.split(inner - 2 - title_width);
+                repeat(writer, p(Piece::Horizontal), left)?;
+                write!(writer, " {title} ")?;
+                repeat(writer, p(Piece::Horizontal), right)?;
+            }
+            _ => repeat(writer, p(Piece::Horizontal), inner)?,
+        }
+        writer.write_char(p(Piece::DownLeft))?;
+        writer.write_str("\n")?;
+
+        // content
+        let padding = self.padding.min(inner / 2);
+        let available = inner - 2 * padding;
+        let mut lines = self.content.lines();
+        for _ in 0..self.height() - 2 {
+            let (line, line_width) = str_truncate_width(lines.next().unwrap_or(""), available);
+            let (left, right) = self.align.split(available - line_width);
+            writer.write_char(p(Piece::Vertical))?;
+            repeat(writer, ' ', padding + left)?;
+            writer.write_str(line)?;
+            repeat(writer, ' ', right + padding)?;
+            writer.write_char(p(Piece::Vertical))?;
+            writer.write_str("\n")?;
+        }
+
+        // bottom border
+        writer.write_char(p(Piece::UpRight))?;
+        repeat(writer, p(Piece::Horizontal), inner)?;
+        writer.write_char(p(Piece::UpLeft))
+    }
+}
+
+impl fmt::Display for BoxFrame<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f)
+    }
+}
+
+/// Writes the `character` `count` times.
+pub(super) fn repeat<W: fmt::Write>(writer: &mut W, character: char, count: usize) -> fmt::Result {
+    (0..count).try_for_each(|_| writer.write_char(character))
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn sizes() {
+        let frame = BoxFrame::new(BoxDrawingThickness::Light)
+            .with_content("a wide 漢字 line\nend")
+            .with_title("title")
+            .with_width(8)
+            .with_height(5)
+            .with_align(Alignment::Right);
+        assert_eq![
+            frame.to_string(),
+            "┌ titl ┐\n│a wide│\n│   end│\n│      │\n└──────┘"
+        ];
+
+        let tiny = BoxFrame::new(BoxDrawingThickness::Double).with_width(0);
+        assert_eq![tiny.to_string(), "╔╗\n╚╝"];
+    }
+}
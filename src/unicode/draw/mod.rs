@@ -3,9 +3,21 @@
 //! Drawing with unicode.
 //
 
+pub mod block;
 pub mod box_drawing;
+pub mod braille;
+pub mod canvas;
+pub mod frame;
+#[cfg(feature = "alloc")]
+pub mod table;
+pub mod tree;
 
+pub use all::*;
 pub(crate) mod all {
     #[doc(inline)]
-    pub use super::box_drawing::*;
+    pub use super::{block::*, box_drawing::*, braille::*, canvas::*, frame::*, tree::*};
+
+    #[doc(inline)]
+    #[cfg(feature = "alloc")]
+    pub use super::table::*;
 }
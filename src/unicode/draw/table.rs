@@ -0,0 +1,547 @@
+// textos::unicode::draw::table
+//
+//! Tables.
+//
+
+use super::{
+    box_drawing::{BoxDrawing, BoxDrawingArms, BoxDrawingThickness, BoxDrawingWeight},
+    frame::repeat,
+};
+use crate::{
+    fmt::Alignment,
+    textual::Textual,
+    unicode::width::{str_truncate_width, str_width},
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A table of text cells, drawn with box-drawing characters.
+///
+/// The widths of the columns are measured in terminal columns, and cells can
+/// have several lines. Cells that don't fit in their column are wrapped or
+/// truncated, depending on the [`TableOverflow`].
+///
+/// It can be rendered into any [`fmt::Write`], or into a `String` by
+/// using [`Display`][fmt::Display].
+///
+/// # Examples
+/// ```
+/// use textos::{fmt::Alignment, unicode::draw::{BoxDrawingThickness::*, table::Table}};
+///
+/// let mut t = Table::new(Light)
+///     .with_header(["name", "ħ"])
+///     .with_header_rule(Double)
+///     .with_column_align(1, Alignment::Right);
+/// t.push_row(["alpha", "1"]);
+/// t.push_row(["漢字", "22"]);
+///
+/// assert_eq![t.to_string(), "\
+/// ┌───────┬────┐
+/// │ name  │  ħ │
+/// ╞═══════╪════╡
+/// │ alpha │  1 │
+/// │ 漢字  │ 22 │
+/// └───────┴────┘"];
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct Table {
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    aligns: Vec<Alignment>,
+    border: BoxDrawingThickness,
+    header_rule: BoxDrawingThickness,
+    inner: BoxDrawingThickness,
+    row_rules: bool,
+    overflow: TableOverflow,
+    max_width: Option<usize>,
+    padding: usize,
+}
+
+/// What to do with the cells that don't fit in their column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TableOverflow {
+    /// Wraps the lines at whitespace, and splits the words that don't fit.
+    #[default]
+    Wrap,
+
+    /// Truncates the lines, ending them with an ellipsis (`…`).
+    Truncate,
+}
+
+impl Table {
+    /// Returns a new empty table, with all the lines of the given `thickness`.
+    #[inline]
+    pub const fn new(thickness: BoxDrawingThickness) -> Self {
+        Self {
+            header: None,
+            rows: Vec::new(),
+            aligns: Vec::new(),
+            border: thickness,
+            header_rule: thickness,
+            inner: thickness,
+            row_rules: false,
+            overflow: TableOverflow::Wrap,
+            max_width: None,
+            padding: 1,
+        }
+    }
+
+    /// Sets the `header` cells.
+    #[must_use]
+    pub fn with_header<T: Textual>(mut self, header: impl IntoIterator<Item = T>) -> Self {
+        self.header = Some(header.into_iter().map(|c| c.to_string()).collect());
+        self
+    }
+
+    /// Appends a row of `cells`.
+    pub fn push_row<T: Textual>(&mut self, cells: impl IntoIterator<Item = T>) {
+        self.rows
+            .push(cells.into_iter().map(|c| c.to_string()).collect());
+    }
+
+    /// Appends a row of `cells`.
+    #[inline]
+    #[must_use]
+    pub fn with_row<T: Textual>(mut self, cells: impl IntoIterator<Item = T>) -> Self {
+        self.push_row(cells);
+        self
+    }
+
+    /// Sets the thickness of the outer border.
+    #[inline]
+    #[must_use]
+    pub const fn with_border(mut self, thickness: BoxDrawingThickness) -> Self {
+        self.border = thickness;
+        self
+    }
+
+    /// Sets the thickness of the rule between the header and the rows.
+    #[inline]
+    #[must_use]
+    pub const fn with_header_rule(mut self, thickness: BoxDrawingThickness) -> Self {
+        self.header_rule = thickness;
+        self
+    }
+
+    /// Sets the thickness of the inner rules, between columns and between rows.
+    #[inline]
+    #[must_use]
+    pub const fn with_inner(mut self, thickness: BoxDrawingThickness) -> Self {
+        self.inner = thickness;
+        self
+    }
+
+    /// Sets whether to draw rules between the rows. It's `false` by default.
+    #[inline]
+    #[must_use]
+    pub const fn with_row_rules(mut self, row_rules: bool) -> Self {
+        self.row_rules = row_rules;
+        self
+    }
+
+    /// Sets the alignment of the column at `index`. It's `Left` by default.
+    #[must_use]
+    pub fn with_column_align(mut self, index: usize, align: Alignment) -> Self {
+        if self.aligns.len() <= index {
+            self.aligns.resize(index + 1, Alignment::Left);
+        }
+        self.aligns[index] = align;
+        self
+    }
+
+    /// Sets what to do with the cells that don't fit. It's `Wrap` by default.
+    #[inline]
+    #[must_use]
+    pub const fn with_overflow(mut self, overflow: TableOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the maximum total width, including the borders.
+    ///
+    /// It's a best-effort limit: the widest columns are narrowed to fit,
+    /// but never under 1, and the borders and padding are never reduced,
+    /// so the table can still be wider when they don't fit.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the `padding` on each side of the cells. It's 1 by default.
+    #[inline]
+    #[must_use]
+    pub const fn with_padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    //
+
+    /// Returns the number of columns.
+    pub fn columns(&self) -> usize {
+        self.header
+            .iter()
+            .chain(&self.rows)
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the width of each column, without the padding.
+    pub fn column_widths(&self) -> Vec<usize> {
+        let mut widths = vec![0; self.columns()];
+        for row in self.header.iter().chain(&self.rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.lines().map(str_width).max().unwrap_or(0));
+            }
+        }
+        if let Some(max_width) = self.max_width {
+            let frame = widths.len().saturating_mul(2 * self.padding + 1) + 1;
+            let budget = max_width.saturating_sub(frame);
+            let fit = |limit: usize| widths.iter().map(|w| (*w).min(limit)).sum::<usize>();
+            let widest = widths.iter().copied().max().unwrap_or(0);
+            if fit(widest) > budget {
+                // the smallest shared limit that keeps the columns within budget
+                let (mut low, mut high) = (1, widest);
+                while low < high {
+                    let mid = low + (high - low) / 2;
+                    if fit(mid) >= budget {
+                        high = mid;
+                    } else {
+                        low = mid + 1;
+                    }
+                }
+                // and the remaining excess is taken from the last capped columns
+                let mut excess = if low > 1 { fit(low) - budget } else { 0 };
+                for width in widths.iter_mut().rev() {
+                    if *width >= low && excess > 0 {
+                        *width = low - 1;
+                        excess -= 1;
+                    } else {
+                        *width = (*width).min(low);
+                    }
+                }
+            }
+        }
+        widths
+    }
+
+    /// Renders the table into the given `writer`.
+    ///
+    /// There's no line ending after the bottom border.
+    ///
+    /// The lines of [`Ascii`][BoxDrawingThickness::Ascii] thickness, and their
+    /// junctions with other lines, are rendered with ASCII characters.
+    /// The cells are left as is.
+    pub fn render<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        let widths = self.column_widths();
+        if widths.is_empty() {
+            return Ok(());
+        }
+        let (border, inner) = (self.border, self.inner);
+        let outer = border.weight();
+
+        self.render_rule(writer, &widths, border, None, Some(outer))?;
+        if let Some(header) = &self.header {
+            self.render_row(writer, &widths, header)?;
+            self.render_rule(writer, &widths, self.header_rule, Some(outer), Some(outer))?;
+        }
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 && self.row_rules {
+                self.render_rule(writer, &widths, inner, Some(outer), Some(outer))?;
+            }
+            self.render_row(writer, &widths, row)?;
+        }
+        self.render_rule(writer, &widths, border, Some(outer), None)
+    }
+
+    /// Returns the box-drawing character of the given `arms`,
+    /// converted to ASCII if any of the `lines` that meet in it is `Ascii`.
+    fn glyph(arms: BoxDrawingArms, lines: &[BoxDrawingThickness]) -> char {
+        let c = arms.to_char_lossy();
+        if lines.contains(&BoxDrawingThickness::Ascii) {
+            BoxDrawing::to_ascii(c)
+        } else {
+            c
+        }
+    }
+
+    /// Renders a horizontal rule of the given `thickness`, followed by a line feed
+    /// if there's a row below.
+    ///
+    /// The `up` and `down` weights are the outer vertical borders
+    /// above and below the rule, if any.
+    fn render_rule<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        widths: &[usize],
+        thickness: BoxDrawingThickness,
+        up: Option<BoxDrawingWeight>,
+        down: Option<BoxDrawingWeight>,
+    ) -> fmt::Result {
+        let none = BoxDrawingWeight::None;
+        let (weight, inner) = (thickness.weight(), self.inner.weight());
+        let junction = |vertical, vertical_thickness, left, right| {
+            let (u, d) = (
+                up.map_or(none, |_| vertical),
+                down.map_or(none, |_| vertical),
+            );
+            let arms = BoxDrawingArms::new(u, right, d, left);
+            Self::glyph(arms, &[thickness, vertical_thickness])
+        };
+        let horizontal = Self::glyph(
+            BoxDrawingArms::new(none, weight, none, weight),
+            &[thickness],
+        );
+        let outer = up.or(down).unwrap_or(none);
+        // outer corners keep the arcs of round borders
+        let corner = |c| match (self.border, c) {
+            (BoxDrawingThickness::Round, '┌') => '╭',
+            (BoxDrawingThickness::Round, '┐') => '╮',
+            (BoxDrawingThickness::Round, '└') => '╰',
+            (BoxDrawingThickness::Round, '┘') => '╯',
+            (_, c) => c,
+        };
+
+        writer.write_char(corner(junction(outer, self.border, none, weight)))?;
+        for (i, width) in widths.iter().enumerate() {
+            repeat(writer, horizontal, width + 2 * self.padding)?;
+            if i + 1 < widths.len() {
+                writer.write_char(junction(inner, self.inner, weight, weight))?;
+            }
+        }
+        writer.write_char(corner(junction(outer, self.border, weight, none)))?;
+        if down.is_some() {
+            writer.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    /// Renders a row of cells, followed by a line feed.
+    fn render_row<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        widths: &[usize],
+        row: &[String],
+    ) -> fmt::Result {
+        let none = BoxDrawingWeight::None;
+        let border = self.border.weight();
+        let inner = self.inner.weight();
+        let outer = Self::glyph(
+            BoxDrawingArms::new(border, none, border, none),
+            &[self.border],
+        );
+        let inner = Self::glyph(BoxDrawingArms::new(inner, none, inner, none), &[self.inner]);
+
+        let cells: Vec<Vec<&str>> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, width)| {
+                let cell = row.get(i).map_or("", String::as_str);
+                self.fit_cell(cell, *width)
+            })
+            .collect();
+        let height = cells.iter().map(Vec::len).max().unwrap_or(1).max(1);
+
+        for line in 0..height {
+            writer.write_char(outer)?;
+            for (i, (width, lines)) in widths.iter().zip(&cells).enumerate() {
+                let text = lines.get(line).copied().unwrap_or("");
+                let align = self.aligns.get(i).copied().unwrap_or_default();
+                let text_width = str_width(text);
+                if text_width <= *width {
+                    let (left, right) = align.split(width - text_width);
+                    repeat(writer, ' ', self.padding + left)?;
+                    writer.write_str(text)?;
+                    repeat(writer, ' ', right + self.padding)?;
+                } else {
+                    // truncated, ending with an ellipsis
+                    let (text, text_width) = str_truncate_width(text, width.saturating_sub(1));
+                    repeat(writer, ' ', self.padding)?;
+                    writer.write_str(text)?;
+                    if *width > 0 {
+                        writer.write_char('…')?;
+                    }
+                    repeat(
+                        writer,
+                        ' ',
+                        width.saturating_sub(text_width + 1) + self.padding,
+                    )?;
+                }
+                writer.write_char(if i + 1 < widths.len() { inner } else { outer })?;
+            }
+            writer.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    /// Returns the lines of the `cell` that fit in the `width`.
+    fn fit_cell<'s>(&self, cell: &'s str, width: usize) -> Vec<&'s str> {
+        let mut lines = Vec::new();
+        for line in cell.lines() {
+            if str_width(line) <= width {
+                lines.push(line);
+            } else {
+                match self.overflow {
+                    TableOverflow::Wrap => wrap_line(line, width, &mut lines),
+                    TableOverflow::Truncate => lines.push(line),
+                }
+            }
+        }
+        lines
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f)
+    }
+}
+
+/// Wraps the `line` at whitespace, splitting the words wider than `width`,
+/// and appends the resulting lines.
+fn wrap_line<'s>(line: &'s str, width: usize, lines: &mut Vec<&'s str>) {
+    let width = width.max(1);
+    let (mut start, mut end, mut current) = (0, 0, 0);
+    for (byte, word) in line.split_word_bound_indices() {
+        let word_width = str_width(word);
+        if current + word_width <= width {
+            end = byte + word.len();
+            current += word_width;
+            continue;
+        }
+        if end > start {
+            lines.push(line[start..end].trim_end());
+        }
+        if word.trim().is_empty() {
+            start = byte + word.len();
+            end = start;
+            current = 0;
+            continue;
+        }
+        // split the word by graphemes
+        let mut rest = word;
+        let mut rest_start = byte;
+        loop {
+            let (piece, piece_width) = str_truncate_width(rest, width);
+            let piece = if piece.is_empty() {
+                rest.graphemes(true).next().unwrap_or(rest)
+            } else {
+                piece
+            };
+            if piece.len() == rest.len() {
+                start = rest_start;
+                end = rest_start + rest.len();
+                current = piece_width;
+                break;
+            }
+            lines.push(piece);
+            rest = &rest[piece.len()..];
+            rest_start += piece.len();
+        }
+    }
+    if end > start {
+        lines.push(line[start..end].trim_end());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use BoxDrawingThickness::*;
+
+    #[test]
+    fn overflow() {
+        let rows = [["id", "a long description"], ["2", "supercalifragilistic"]];
+        let mut wrap = Table::new(Light)
+            .with_border(Heavy)
+            .with_row_rules(true)
+            .with_max_width(18);
+        let mut trunc = wrap.clone().with_overflow(TableOverflow::Truncate);
+        for row in rows {
+            wrap.push_row(row);
+            trunc.push_row(row);
+        }
+        assert_eq![vec![2, 9], wrap.column_widths()];
+        assert_eq![
+            wrap.to_string(),
+            "\
+┏━━━━┯━━━━━━━━━━━┓
+┃ id │ a long    ┃
+┃    │ descripti ┃
+┃    │ on        ┃
+┠────┼───────────┨
+┃ 2  │ supercali ┃
+┃    │ fragilist ┃
+┃    │ ic        ┃
+┗━━━━┷━━━━━━━━━━━┛"
+        ];
+        assert_eq![
+            trunc.to_string(),
+            "\
+┏━━━━┯━━━━━━━━━━━┓
+┃ id │ a long d… ┃
+┠────┼───────────┨
+┃ 2  │ supercal… ┃
+┗━━━━┷━━━━━━━━━━━┛"
+        ];
+
+        // the widest columns share the limit, and the last ones narrow first
+        let t = Table::new(Light).with_row(["aaaaa", "bbbbb", "c"]);
+        assert_eq![vec![3, 3, 1], t.clone().with_max_width(17).column_widths()];
+        assert_eq![vec![3, 2, 1], t.clone().with_max_width(16).column_widths()];
+        // the frame is not reduced, nor the columns under 1
+        let narrow = t.with_row(["", "", ""]).with_max_width(3);
+        assert_eq![vec![1, 1, 1], narrow.column_widths()];
+        assert_eq![narrow.to_string().lines().next(), Some("┌───┬───┬───┐")];
+    }
+
+    #[test]
+    fn ragged() {
+        let t = Table::new(Double).with_row(["a"]).with_row(["b", "c\nd"]);
+        assert_eq![
+            t.to_string(),
+            "╔═══╦═══╗\n║ a ║   ║\n║ b ║ c ║\n║   ║ d ║\n╚═══╩═══╝"
+        ];
+        assert_eq!["", Table::new(Light).to_string()];
+
+        let ascii = Table {
+            border: Ascii,
+            header_rule: Ascii,
+            inner: Ascii,
+            ..t
+        };
+        let ascii = ascii.with_header(["x", "y"]);
+        assert_eq![
+            ascii.to_string(),
+            "+---+---+\n| x | y |\n+---+---+\n| a |   |\n| b | c |\n|   | d |\n+---+---+"
+        ];
+        // the ASCII lines are converted with their junctions
+        let mixed = Table::new(Light)
+            .with_inner(Ascii)
+            .with_header(["x", "y"])
+            .with_header_rule(Double)
+            .with_row(["a", "b"]);
+        assert_eq![
+            mixed.to_string(),
+            "┌───+───┐\n│ x | y │\n╞═══+═══╡\n│ a | b │\n└───+───┘"
+        ];
+        let header = Table::new(Heavy).with_header(["x"]).with_header_rule(Ascii);
+        assert_eq![header.to_string(), "┏━━━┓\n┃ x ┃\n+---+\n┗━━━┛"];
+
+        // the cells keep their box-drawing characters
+        let cells = Table::new(Ascii).with_row(["─┼─", "╭╮"]);
+        assert_eq![
+            cells.to_string(),
+            "+-----+----+\n| ─┼─ | ╭╮ |\n+-----+----+"
+        ];
+    }
+}
@@ -0,0 +1,385 @@
+// textos::unicode::draw::tree
+//
+//! Trees with box-drawing connectors.
+//
+
+use super::box_drawing::{BoxDrawing, BoxDrawingPiece as Piece, BoxDrawingThickness};
+use crate::textual::Textual;
+use core::fmt::{self, Write};
+
+/// A node of a tree that can be rendered with a [`TreeRenderer`].
+///
+/// # Examples
+/// ```
+/// use textos::unicode::draw::{BoxDrawingThickness, TreeNode, TreeRenderer};
+///
+/// struct Dir(&'static str, Vec<Dir>);
+///
+/// impl TreeNode for Dir {
+///     type Label<'a> = &'a str;
+///     type Children<'a> = core::slice::Iter<'a, Dir>;
+///
+///     fn label(&self) -> &str {
+///         self.0
+///     }
+///     fn children(&self) -> Self::Children<'_> {
+///         self.1.iter()
+///     }
+/// }
+///
+/// let src = Dir("src", vec![Dir("lib.rs", vec![]), Dir("draw", vec![Dir("tree.rs", vec![])])]);
+///
+/// let tree = TreeRenderer::new(BoxDrawingThickness::Light);
+/// assert_eq![tree.display(&src).to_string(), "\
+/// src
+/// ├── lib.rs
+/// └── draw
+///     └── tree.rs"];
+/// ```
+pub trait TreeNode {
+    /// The type of the label.
+    type Label<'a>: Textual
+    where
+        Self: 'a;
+
+    /// The iterator over the children.
+    type Children<'a>: Iterator<Item = &'a Self>
+    where
+        Self: 'a;
+
+    /// Returns the label of the node.
+    fn label(&self) -> Self::Label<'_>;
+
+    /// Returns an iterator over the children of the node.
+    fn children(&self) -> Self::Children<'_>;
+}
+
+/// A tree node with a `label` and a slice of `children`, that can be built
+/// in constant contexts.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::draw::{BoxDrawingThickness, TreeItem, TreeRenderer};
+///
+/// const TREE: TreeItem<&str> = TreeItem::new("a", &[
+///     TreeItem::new("b", &[TreeItem::leaf("c")]),
+///     TreeItem::leaf("d"),
+/// ]);
+///
+/// let tree = TreeRenderer::new(BoxDrawingThickness::Round);
+/// assert_eq![tree.display(&TREE).to_string(), "a\n├── b\n│   ╰── c\n╰── d"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TreeItem<'a, L: Textual> {
+    /// The label of the node.
+    pub label: L,
+    /// The children of the node.
+    pub children: &'a [TreeItem<'a, L>],
+}
+
+impl<'a, L: Textual> TreeItem<'a, L> {
+    /// Returns a new node with the given `label` and `children`.
+    #[inline]
+    pub const fn new(label: L, children: &'a [TreeItem<'a, L>]) -> Self {
+        Self { label, children }
+    }
+
+    /// Returns a new node with the given `label` and no children.
+    #[inline]
+    pub const fn leaf(label: L) -> Self {
+        Self {
+            label,
+            children: &[],
+        }
+    }
+}
+
+impl<'t, L: Textual> TreeNode for TreeItem<'t, L> {
+    type Label<'a>
+        = &'a L
+    where
+        Self: 'a;
+    type Children<'a>
+        = core::slice::Iter<'a, TreeItem<'t, L>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn label(&self) -> &L {
+        &self.label
+    }
+    #[inline]
+    fn children(&self) -> Self::Children<'_> {
+        self.children.iter()
+    }
+}
+
+/// Renders trees of [`TreeNode`]s, with box-drawing connectors.
+///
+/// The connectors follow the [`BoxDrawingThickness`], and the
+/// [`Ascii`][BoxDrawingThickness::Ascii] thickness renders them as
+/// `|--`, `` `-- `` and `|`.
+///
+/// The tree is streamed into the writer, without allocating.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::draw::{BoxDrawingThickness, TreeItem, TreeRenderer};
+///
+/// let grandchildren = [TreeItem::leaf("c")];
+/// let children = [TreeItem::new("b", &grandchildren), TreeItem::leaf("d")];
+/// let tree = TreeItem::new("a", &children);
+///
+/// let ascii = TreeRenderer::new(BoxDrawingThickness::Ascii);
+/// assert_eq![ascii.display(&tree).to_string(), "a\n|-- b\n|   `-- c\n`-- d"];
+///
+/// let shallow = TreeRenderer::new(BoxDrawingThickness::Heavy).with_max_depth(1);
+/// assert_eq![shallow.display(&tree).to_string(), "a\n┣━━ b\n┗━━ d"];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TreeRenderer {
+    thickness: BoxDrawingThickness,
+    max_depth: Option<usize>,
+}
+
+/// The chain of ancestors of a node, from the closest to the root,
+/// where each one remembers whether it's the last of its siblings.
+struct Ancestors<'p> {
+    last: bool,
+    parent: Option<&'p Ancestors<'p>>,
+}
+
+impl TreeRenderer {
+    /// Returns a new renderer with the given `thickness`, and no depth limit.
+    #[inline]
+    pub const fn new(thickness: BoxDrawingThickness) -> Self {
+        Self {
+            thickness,
+            max_depth: None,
+        }
+    }
+
+    /// Sets the maximum depth of the rendered nodes, where the root has depth 0.
+    ///
+    /// The deeper nodes are not rendered.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Returns the thickness of the connectors.
+    #[inline]
+    pub const fn thickness(&self) -> BoxDrawingThickness {
+        self.thickness
+    }
+
+    /// Returns the maximum depth, if any.
+    #[inline]
+    pub const fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    //
+
+    /// Renders the tree starting at `root` into the given `writer`.
+    ///
+    /// Each node is rendered in its own line, with no line ending after the last.
+    /// The continuation lines of multi-line labels are indented like the
+    /// children of their node.
+    pub fn render<N: TreeNode, W: fmt::Write>(&self, root: &N, writer: &mut W) -> fmt::Result {
+        self.render_label(root, None, writer)?;
+        self.render_children(root, 1, None, writer)
+    }
+
+    /// Returns a wrapper that renders the tree starting at `root`
+    /// by using [`Display`][fmt::Display].
+    #[inline]
+    pub const fn display<'a, N: TreeNode>(&'a self, root: &'a N) -> TreeDisplay<'a, N> {
+        TreeDisplay {
+            renderer: self,
+            root,
+        }
+    }
+
+    /// Renders the children of the `node`, which are at `depth`.
+    fn render_children<N: TreeNode, W: fmt::Write>(
+        &self,
+        node: &N,
+        depth: usize,
+        ancestors: Option<&Ancestors>,
+        writer: &mut W,
+    ) -> fmt::Result {
+        if self.max_depth.is_some_and(|max| depth > max) {
+            return Ok(());
+        }
+        let mut children = node.children().peekable();
+        while let Some(child) = children.next() {
+            let last = children.peek().is_none();
+            writer.write_char('\n')?;
+            self.render_prefix(ancestors, writer)?;
+            self.render_connector(last, writer)?;
+
+            let ancestors = Ancestors {
+                last,
+                parent: ancestors,
+            };
+            self.render_label(child, Some(&ancestors), writer)?;
+            self.render_children(child, depth + 1, Some(&ancestors), writer)?;
+        }
+        Ok(())
+    }
+
+    /// Renders the label of the `node`, prefixing its continuation lines
+    /// with the vertical lines of its `ancestors`, the node included.
+    fn render_label<N: TreeNode, W: fmt::Write>(
+        &self,
+        node: &N,
+        ancestors: Option<&Ancestors>,
+        writer: &mut W,
+    ) -> fmt::Result {
+        let mut label = LabelWriter {
+            renderer: self,
+            ancestors,
+            writer,
+        };
+        write!(label, "{}", node.label())
+    }
+
+    /// Renders the vertical lines of the ancestors that have more siblings below.
+    fn render_prefix<W: fmt::Write>(
+        &self,
+        ancestors: Option<&Ancestors>,
+        writer: &mut W,
+    ) -> fmt::Result {
+        let Some(ancestors) = ancestors else {
+            return Ok(());
+        };
+        // the root-most ancestor goes first
+        self.render_prefix(ancestors.parent, writer)?;
+        if ancestors.last {
+            writer.write_str("    ")
+        } else {
+            writer.write_char(BoxDrawing::piece(Piece::Vertical, self.thickness))?;
+            writer.write_str("   ")
+        }
+    }
+
+    /// Renders the connector of a node, and the space before its label.
+    fn render_connector<W: fmt::Write>(&self, last: bool, writer: &mut W) -> fmt::Result {
+        let junction = match (self.thickness, last) {
+            (BoxDrawingThickness::Ascii, false) => BoxDrawing::V4,
+            (BoxDrawingThickness::Ascii, true) => '`',
+            (t, false) => BoxDrawing::piece(Piece::VerticalRight, t),
+            (t, true) => BoxDrawing::piece(Piece::UpRight, t),
+        };
+        let h = BoxDrawing::piece(Piece::Horizontal, self.thickness);
+        writer.write_char(junction)?;
+        writer.write_char(h)?;
+        writer.write_char(h)?;
+        writer.write_char(' ')
+    }
+}
+
+/// Writes a label, prefixing each line after the first.
+struct LabelWriter<'r, 'a, W: fmt::Write> {
+    renderer: &'r TreeRenderer,
+    ancestors: Option<&'a Ancestors<'a>>,
+    writer: &'r mut W,
+}
+
+impl<W: fmt::Write> fmt::Write for LabelWriter<'_, '_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut lines = s.split('\n');
+        if let Some(first) = lines.next() {
+            self.writer.write_str(first)?;
+        }
+        for line in lines {
+            self.writer.write_char('\n')?;
+            self.renderer.render_prefix(self.ancestors, self.writer)?;
+            self.writer.write_str(line)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for TreeRenderer {
+    /// Returns a renderer with light connectors and no depth limit.
+    #[inline]
+    fn default() -> Self {
+        Self::new(BoxDrawingThickness::Light)
+    }
+}
+
+/// Renders a tree by using [`Display`][fmt::Display].
+///
+/// It's returned by [`TreeRenderer::display`].
+pub struct TreeDisplay<'a, N: TreeNode> {
+    renderer: &'a TreeRenderer,
+    root: &'a N,
+}
+
+impl<N: TreeNode> fmt::Display for TreeDisplay<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.renderer.render(self.root, f)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn nested() {
+        const TREE: TreeItem<&str> = TreeItem::new(
+            "a",
+            &[
+                TreeItem::new("b", &[TreeItem::leaf("c"), TreeItem::leaf("d")]),
+                TreeItem::new("e", &[TreeItem::new("f", &[TreeItem::leaf("g")])]),
+            ],
+        );
+        let tree = TreeRenderer::new(BoxDrawingThickness::Light);
+        assert_eq![
+            tree.display(&TREE).to_string(),
+            "a\n├── b\n│   ├── c\n│   └── d\n└── e\n    └── f\n        └── g"
+        ];
+        let shallow = tree.with_max_depth(0);
+        assert_eq!["a", shallow.display(&TREE).to_string()];
+    }
+
+    #[test]
+    fn last_child() {
+        const TREE: TreeItem<&str> = TreeItem::new(
+            "a",
+            &[
+                TreeItem::new("b", &[TreeItem::leaf("c")]),
+                TreeItem::leaf("d"),
+            ],
+        );
+        let tree = TreeRenderer::new(BoxDrawingThickness::Double);
+        assert_eq![
+            tree.display(&TREE).to_string(),
+            "a\n╠══ b\n║   ╚══ c\n╚══ d"
+        ];
+        assert_eq!["a", tree.display(&TreeItem::leaf("a")).to_string()];
+    }
+
+    #[test]
+    fn multi_line() {
+        const TREE: TreeItem<&str> = TreeItem::new(
+            "a\nA",
+            &[
+                TreeItem::new("b\nB", &[TreeItem::leaf("c\nC")]),
+                TreeItem::leaf("d\nD"),
+            ],
+        );
+        let tree = TreeRenderer::new(BoxDrawingThickness::Ascii);
+        assert_eq![
+            tree.display(&TREE).to_string(),
+            "a\nA\n|-- b\n|   B\n|   `-- c\n|       C\n`-- d\n    D"
+        ];
+    }
+}
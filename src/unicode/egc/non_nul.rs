@@ -1,6 +1,6 @@
 // textos::unicode::egc::non_nul
 //
-//!
+//
 //
 // TOC
 // - definitions
@@ -11,7 +11,11 @@ use crate::{
     error::{TextosError as Error, TextosResult as Result},
     macros::impl_sized_alias,
     textual::Textual,
-    unicode::{char::*, egc::Egc, string::StaticNonNulString},
+    unicode::{
+        char::*,
+        egc::Egc,
+        string::{impl_str_search, StaticNonNulString},
+    },
 };
 #[cfg(feature = "alloc")]
 use alloc::{ffi::CString, str::Chars};
@@ -180,6 +184,9 @@ impl<const CAP: usize> StaticNonNulEgc<CAP> {
     }
 
     /// Returns a mutable byte slice of the inner string slice.
+    ///
+    /// # Safety
+    /// The content must be valid UTF-8.
     #[inline]
     #[cfg(feature = "unsafe")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe")))]
@@ -210,6 +217,9 @@ impl<const CAP: usize> StaticNonNulEgc<CAP> {
     }
 
     /// Returns the mutable inner string slice.
+    ///
+    /// # Safety
+    /// The content must be valid UTF-8.
     #[cfg(feature = "unsafe")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe")))]
     pub unsafe fn as_str_mut(&mut self) -> &mut str {
@@ -219,7 +229,7 @@ impl<const CAP: usize> StaticNonNulEgc<CAP> {
     /// Returns an iterator over the `chars` of this grapheme cluster.
     #[cfg(feature = "alloc")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
-    pub fn chars(&self) -> Chars {
+    pub fn chars(&self) -> Chars<'_> {
         self.0.chars()
     }
 
@@ -234,6 +244,8 @@ impl<const CAP: usize> StaticNonNulEgc<CAP> {
     //
 }
 
+impl_str_search![StaticNonNulEgc => StaticNonNulString];
+
 /* traits */
 
 impl<const CAP: usize> Textual for StaticNonNulEgc<CAP> {}
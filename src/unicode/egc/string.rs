@@ -1,9 +1,12 @@
 // textos::unicode::egc::string
 //
-//!
+//
 //
 
-use crate::unicode::char::*;
+use crate::unicode::{
+    char::*,
+    string::{impl_str_search, StrPattern},
+};
 use core::fmt;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -81,12 +84,44 @@ impl StringEgc {
         self.0.clear();
     }
 
+    /// Returns the inner string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
     /// Returns an iterator over the `chars` of this grapheme cluster.
     #[inline]
     #[cfg(feature = "alloc")]
-    pub fn chars(&self) -> CharIterator {
+    pub fn chars(&self) -> CharIterator<'_> {
         self.0.chars()
     }
+
+    //
+
+    impl_str_search![@search];
+
+    /// Returns a new string with all the matches of the pattern
+    /// replaced with the `to` string slice.
+    #[inline]
+    pub fn replace<P: StrPattern>(&self, from: P, to: &str) -> String {
+        self.replacen(from, to, usize::MAX)
+    }
+
+    /// Returns a new string with the first `count` matches of the pattern
+    /// replaced with the `to` string slice.
+    pub fn replacen<P: StrPattern>(&self, from: P, to: &str, count: usize) -> String {
+        let string = self.as_str();
+        let mut new = String::with_capacity(string.len());
+        let mut last_end = 0;
+        for (start, part) in from.match_indices_in(string).take(count) {
+            new.push_str(&string[last_end..start]);
+            new.push_str(to);
+            last_end = start + part.len();
+        }
+        new.push_str(&string[last_end..]);
+        new
+    }
 }
 
 /* traits */
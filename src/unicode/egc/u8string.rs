@@ -1,6 +1,6 @@
 // textos::unicode::egc::u8string
 //
-//!
+//
 //
 // TOC
 // - definitions
@@ -11,13 +11,17 @@ use crate::{
     error::{TextosError as Error, TextosResult as Result},
     macros::impl_sized_alias,
     textual::Textual,
-    unicode::{char::*, egc::Egc, string::StaticU8String},
+    unicode::{
+        char::*,
+        egc::Egc,
+        string::{impl_str_search, StaticU8String},
+    },
 };
 #[cfg(feature = "alloc")]
 use alloc::{ffi::CString, str::Chars};
 use core::fmt;
 use devela::codegen::paste;
-// use unicode_segmentation::UnicodeSegmentation;
+use unicode_segmentation::UnicodeSegmentation;
 
 /* definitions */
 
@@ -124,6 +128,35 @@ impl<const CAP: usize> StaticU8Egc<CAP> {
         Self::from_char32(Char32(c))
     }
 
+    /// Tries to create a new `StaticU8Egc` from the given `string` slice,
+    /// which must be a single extended grapheme cluster.
+    ///
+    /// # Errors
+    /// Returns [`NotSingleGrapheme`][Error::NotSingleGrapheme] if the `string`
+    /// is empty or has more than one cluster, or
+    /// [`NotEnoughCapacity`][Error::NotEnoughCapacity] if it doesn't fit in `CAP`.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::{error::TextosError, unicode::egc::StaticU8Egc};
+    ///
+    /// assert_eq![StaticU8Egc::<8>::try_from_str("e\u{301}").unwrap().as_str(), "e\u{301}"];
+    /// assert_eq![StaticU8Egc::<8>::try_from_str("ab"), Err(TextosError::NotSingleGrapheme)];
+    /// assert_eq![StaticU8Egc::<8>::try_from_str(""), Err(TextosError::NotSingleGrapheme)];
+    /// assert_eq![StaticU8Egc::<2>::try_from_str("😀"), Err(TextosError::NotEnoughCapacity(4))];
+    /// ```
+    pub fn try_from_str(string: &str) -> Result<Self> {
+        let mut egcs = string.graphemes(true);
+        match (egcs.next(), egcs.next()) {
+            (Some(egc), None) => {
+                let mut new = Self::new();
+                new.0.try_push_str_complete(egc)?;
+                Ok(new)
+            }
+            _ => Err(Error::NotSingleGrapheme),
+        }
+    }
+
     //
 
     /// Returns the length in bytes.
@@ -169,6 +202,9 @@ impl<const CAP: usize> StaticU8Egc<CAP> {
     }
 
     /// Returns a mutable byte slice of the inner string slice.
+    ///
+    /// # Safety
+    /// The content must be valid UTF-8.
     #[inline]
     #[cfg(feature = "unsafe")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe")))]
@@ -199,6 +235,9 @@ impl<const CAP: usize> StaticU8Egc<CAP> {
     }
 
     /// Returns the mutable inner string slice.
+    ///
+    /// # Safety
+    /// The content must be valid UTF-8.
     #[cfg(feature = "unsafe")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe")))]
     pub unsafe fn as_str_mut(&mut self) -> &mut str {
@@ -208,7 +247,7 @@ impl<const CAP: usize> StaticU8Egc<CAP> {
     /// Returns an iterator over the `chars` of this grapheme cluster.
     #[cfg(feature = "alloc")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
-    pub fn chars(&self) -> Chars {
+    pub fn chars(&self) -> Chars<'_> {
         self.0.chars()
     }
 
@@ -221,6 +260,8 @@ impl<const CAP: usize> StaticU8Egc<CAP> {
     }
 }
 
+impl_str_search![StaticU8Egc => StaticU8String];
+
 /* traits */
 
 impl<const CAP: usize> Textual for StaticU8Egc<CAP> {}
@@ -14,10 +14,17 @@ pub use ::unicode_blocks;
 #[doc(inline)]
 pub use ::unicode_blocks::find_unicode_block;
 
+/// Re-export of the `unicode_width` crate.
+///
+#[doc(inline)]
+pub use ::unicode_width;
+
 pub mod char;
 pub mod draw;
 pub mod egc;
+pub mod number;
 pub mod string;
+pub mod width;
 
 pub use all::*;
 pub(crate) mod all {
@@ -27,6 +34,8 @@ pub(crate) mod all {
         draw::all::*,
         egc::*,
         find_unicode_block,
+        number::*,
         string::all::*,
+        width::*,
     };
 }
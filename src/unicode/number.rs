@@ -0,0 +1,313 @@
+// textos::unicode::number
+//
+//! Numbers written with the decimal digits of any script.
+//!
+//! The digits are the characters of the `Nd` general category, like the
+//! fullwidth `０-９`, the Arabic-Indic `٠-٩` or the Devanagari `०-९`.
+//! All the digits of a number must belong to the same script.
+//!
+//! The signs and decimal separators are normalized too:
+//! - plus: `+`, `＋`.
+//! - minus: `-`, `−`, `－`.
+//! - decimal point: `.`, `．`, `٫`.
+//! - exponent: `e`, `E`, `ｅ`, `Ｅ`.
+//
+
+use crate::{
+    error::{TextosError as Error, TextosResult as Result},
+    unicode::char::UnicodeScalar,
+};
+use core::{iter::Peekable, str::CharIndices};
+
+/// A number type that can be parsed from the decimal digits of any script.
+///
+/// See the [module documentation][self] for the accepted characters.
+pub trait ParseDecimal: Sized {
+    /// Parses the `text` as a number of this type.
+    ///
+    /// # Errors
+    /// Returns [`InvalidNumber`][Error::InvalidNumber] with the byte position
+    /// of the first invalid character, or [`NumberOverflow`][Error::NumberOverflow]
+    /// with the byte position of the digit that doesn't fit.
+    fn parse_decimal(text: &str) -> Result<Self>;
+}
+
+/// Parses the `text` as a number, written with the decimal digits of any script.
+///
+/// # Errors
+/// See [`ParseDecimal::parse_decimal`].
+///
+/// # Examples
+/// ```
+/// use textos::{error::TextosError, unicode::number::parse_decimal};
+///
+/// assert_eq![parse_decimal::<u32>("２０２４").unwrap(), 2024];
+/// assert_eq![parse_decimal::<i8>("−١٢٨").unwrap(), -128];
+/// assert_eq![parse_decimal::<f64>("३.५e२").unwrap(), 350.0];
+///
+/// // the position is in bytes, and the digits can't mix scripts
+/// assert![matches![
+///     parse_decimal::<u32>("４2"),
+///     Err(TextosError::InvalidNumber { position: 3 })
+/// ]];
+/// assert![matches![
+///     parse_decimal::<u8>("256"),
+///     Err(TextosError::NumberOverflow { position: 2 })
+/// ]];
+/// ```
+#[inline]
+pub fn parse_decimal<T: ParseDecimal>(text: &str) -> Result<T> {
+    T::parse_decimal(text)
+}
+
+/// A character of a number, normalized.
+#[derive(Clone, Copy)]
+enum Symbol {
+    Plus,
+    Minus,
+    Point,
+    Exponent,
+    /// A digit `value`, and the `zero` of its script.
+    Digit {
+        value: u32,
+        zero: u32,
+    },
+    Other,
+}
+
+impl Symbol {
+    fn of(c: char) -> Self {
+        match c {
+            '+' | '＋' => Symbol::Plus,
+            '-' | '−' | '－' => Symbol::Minus,
+            '.' | '．' | '٫' => Symbol::Point,
+            'e' | 'E' | 'ｅ' | 'Ｅ' => Symbol::Exponent,
+            _ => match c.to_decimal_digit_unicode() {
+                Some(value) => Symbol::Digit {
+                    value,
+                    zero: c as u32 - value,
+                },
+                None => Symbol::Other,
+            },
+        }
+    }
+}
+
+/// Scans the number in `text`, normalized to ASCII.
+struct Scanner<'a> {
+    text: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    /// The zero of the script of the first digit.
+    zero: Option<u32>,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            chars: text.char_indices().peekable(),
+            zero: None,
+        }
+    }
+
+    /// Returns the position of the next character, or the end of the text.
+    fn position(&mut self) -> usize {
+        self.chars.peek().map_or(self.text.len(), |(i, _)| *i)
+    }
+
+    /// Returns the next symbol, and its position, if it matches the `condition`.
+    fn next_if(&mut self, condition: impl Fn(Symbol) -> bool) -> Option<(usize, Symbol)> {
+        let (i, c) = *self.chars.peek()?;
+        let symbol = Symbol::of(c);
+        if condition(symbol) {
+            self.chars.next();
+            Some((i, symbol))
+        } else {
+            None
+        }
+    }
+
+    /// Scans an optional sign, and emits the minus.
+    fn sign(&mut self, emit: &mut impl FnMut(usize, u8) -> Result<()>) -> Result<()> {
+        match self.next_if(|s| matches![s, Symbol::Plus | Symbol::Minus]) {
+            Some((i, Symbol::Minus)) => emit(i, b'-'),
+            _ => Ok(()),
+        }
+    }
+
+    /// Scans and emits a run of digits, and returns how many there were.
+    fn digits(&mut self, emit: &mut impl FnMut(usize, u8) -> Result<()>) -> Result<usize> {
+        let mut count = 0;
+        while let Some((i, Symbol::Digit { value, zero })) =
+            self.next_if(|s| matches![s, Symbol::Digit { .. }])
+        {
+            if *self.zero.get_or_insert(zero) != zero {
+                return Err(Error::InvalidNumber { position: i });
+            }
+            emit(i, b'0' + value as u8)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Scans the whole text as a number, emitting its ASCII bytes with their
+    /// positions, except for the plus signs.
+    ///
+    /// Floats can have a decimal point and an exponent.
+    fn scan(mut self, float: bool, mut emit: impl FnMut(usize, u8) -> Result<()>) -> Result<()> {
+        let emit = &mut emit;
+        self.sign(emit)?;
+        let mut count = self.digits(emit)?;
+        if float {
+            if let Some((i, _)) = self.next_if(|s| matches![s, Symbol::Point]) {
+                emit(i, b'.')?;
+                count += self.digits(emit)?;
+            }
+            if count > 0 {
+                if let Some((i, _)) = self.next_if(|s| matches![s, Symbol::Exponent]) {
+                    emit(i, b'e')?;
+                    self.sign(emit)?;
+                    if self.digits(emit)? == 0 {
+                        return Err(Error::InvalidNumber {
+                            position: self.position(),
+                        });
+                    }
+                }
+            }
+        }
+        if count == 0 || self.chars.peek().is_some() {
+            return Err(Error::InvalidNumber {
+                position: self.position(),
+            });
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_parse_decimal {
+    (unsigned: $($t:ty),+) => { $(
+        impl ParseDecimal for $t {
+            fn parse_decimal(text: &str) -> Result<Self> {
+                let mut value: $t = 0;
+                Scanner::new(text).scan(false, |position, byte| {
+                    if byte == b'-' {
+                        return Err(Error::InvalidNumber { position });
+                    }
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add((byte - b'0') as $t))
+                        .ok_or(Error::NumberOverflow { position })?;
+                    Ok(())
+                })?;
+                Ok(value)
+            }
+        }
+    )+ };
+    (signed: $($t:ty),+) => { $(
+        impl ParseDecimal for $t {
+            fn parse_decimal(text: &str) -> Result<Self> {
+                let (mut value, mut negative): ($t, bool) = (0, false);
+                Scanner::new(text).scan(false, |position, byte| {
+                    if byte == b'-' {
+                        negative = true;
+                        return Ok(());
+                    }
+                    let digit = (byte - b'0') as $t;
+                    // accumulating the negative numbers apart reaches their minimum
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| if negative { v.checked_sub(digit) } else { v.checked_add(digit) })
+                        .ok_or(Error::NumberOverflow { position })?;
+                    Ok(())
+                })?;
+                Ok(value)
+            }
+        }
+    )+ };
+    (float: $($t:ty),+) => { $(
+        /// The normalized text is parsed with [`str::parse`], so the numbers
+        /// too big become infinite.
+        ///
+        /// # Errors
+        /// Besides the errors of [`ParseDecimal::parse_decimal`], returns
+        /// [`NotEnoughCapacity`][Error::NotEnoughCapacity] if the text is not ASCII
+        /// and its normalized length is greater than 128 bytes.
+        impl ParseDecimal for $t {
+            fn parse_decimal(text: &str) -> Result<Self> {
+                let (mut buffer, mut len) = ([0_u8; FLOAT_CAP], 0);
+                Scanner::new(text).scan(true, |_, byte| {
+                    if let Some(b) = buffer.get_mut(len) {
+                        *b = byte;
+                    }
+                    len += 1;
+                    Ok(())
+                })?;
+                // the ASCII text is already valid, and doesn't need the buffer
+                let normalized = if text.is_ascii() {
+                    text
+                } else if len <= FLOAT_CAP {
+                    core::str::from_utf8(&buffer[..len])?
+                } else {
+                    return Err(Error::NotEnoughCapacity(len));
+                };
+                normalized.parse().map_err(|_| Error::InvalidNumber { position: 0 })
+            }
+        }
+    )+ };
+}
+impl_parse_decimal![unsigned: u8, u16, u32, u64, u128, usize];
+impl_parse_decimal![signed: i8, i16, i32, i64, i128, isize];
+impl_parse_decimal![float: f32, f64];
+
+/// The capacity of the buffer for normalizing floats.
+const FLOAT_CAP: usize = 128;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_position<T: ParseDecimal>(text: &str) -> Option<usize> {
+        match T::parse_decimal(text) {
+            Err(Error::InvalidNumber { position } | Error::NumberOverflow { position }) => {
+                Some(position)
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq![
+            parse_decimal::<i128>("-170141183460469231731687303715884105728").ok(),
+            Some(i128::MIN)
+        ];
+        assert_eq![parse_decimal::<u64>("+۱۲۳").ok(), Some(123)];
+        assert_eq![parse_decimal::<i16>("－৩২৭৬৮").ok(), Some(-32768)];
+        assert_eq![parse_decimal::<i16>("𝟑𝟐𝟕𝟔𝟕").ok(), Some(32767)];
+
+        assert_eq![error_position::<u8>(""), Some(0)];
+        assert_eq![error_position::<u8>("+"), Some(1)];
+        assert_eq![error_position::<u8>("-1"), Some(0)];
+        assert_eq![error_position::<u8>("1 "), Some(1)];
+        assert_eq![error_position::<u8>("1.0"), Some(1)];
+        assert_eq![error_position::<i8>("-129"), Some(3)];
+        assert_eq![error_position::<i8>("٠٠١٢٨"), Some(8)];
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq![parse_decimal::<f64>("١٫٥").ok(), Some(1.5)];
+        assert_eq![parse_decimal::<f64>(".５").ok(), Some(0.5)];
+        assert_eq![parse_decimal::<f32>("2.").ok(), Some(2.0)];
+        assert_eq![parse_decimal::<f64>("-1E-2").ok(), Some(-0.01)];
+        assert_eq![parse_decimal::<f64>("1e999").ok(), Some(f64::INFINITY)];
+
+        assert_eq![error_position::<f64>("."), Some(1)];
+        assert_eq![error_position::<f64>("e5"), Some(0)];
+        assert_eq![error_position::<f64>("1e"), Some(2)];
+        assert_eq![error_position::<f64>("1e+"), Some(3)];
+        assert_eq![error_position::<f64>("1.2.3"), Some(3)];
+        assert_eq![error_position::<f64>("inf"), Some(0)];
+        assert_eq![error_position::<f64>("१.2"), Some(4)];
+    }
+}
@@ -0,0 +1,602 @@
+// textos::unicode::string::interner
+//
+//! String interners.
+//
+// TOC
+// - definitions
+// - Symbol
+// - Interner
+// - StaticInterner
+// - Interned
+// - serde impls
+// - tests
+
+use crate::{
+    error::{TextosError as Error, TextosResult as Result},
+    textual::Textual,
+    unicode::string::StaticU8String,
+};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{fmt, num::NonZeroU32};
+
+/* definitions */
+
+/// A compact handle to a string stored in an [`Interner`] or a [`StaticInterner`].
+///
+/// A symbol only makes sense for the interner that returned it.
+///
+/// `Option<Symbol>` has the same size as `Symbol`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Symbol(NonZeroU32);
+
+/// A string interner, that stores each distinct string only once.
+///
+/// Strings are stored contiguously in a single arena, and looked up
+/// by binary search.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::string::Interner;
+///
+/// let mut i = Interner::new();
+/// let (a, b) = (i.intern("alpha"), i.intern("beta"));
+/// assert_eq![a, i.intern("alpha")];
+/// assert_ne![a, b];
+///
+/// assert_eq![Some("beta"), i.resolve(b)];
+/// assert_eq!["alpha", i.interned(a).unwrap().to_string()];
+/// assert_eq![2, i.len()];
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct Interner {
+    arena: String,
+    // the end of each string in the arena, indexed by symbol.
+    ends: Vec<usize>,
+    // the symbols, sorted by their strings.
+    sorted: Vec<Symbol>,
+}
+
+/// A string interner, backed by a [`StaticU8String`] arena of `CAP` bytes,
+/// with capacity for `N` strings.
+///
+/// Strings are looked up linearly.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::string::StaticInterner;
+///
+/// let mut i = StaticInterner::<32, 4>::new();
+/// let a = i.intern("alpha").unwrap();
+/// assert_eq![a, i.intern("alpha").unwrap()];
+/// assert_eq![Some("alpha"), i.resolve(a)];
+///
+/// assert![i.intern("a string that is too long to fit").is_err()];
+/// ```
+#[derive(Clone, Copy)]
+pub struct StaticInterner<const CAP: usize, const N: usize> {
+    arena: StaticU8String<CAP>,
+    // the end of each string in the arena, indexed by symbol.
+    ends: [u8; N],
+    len: usize,
+}
+
+/// An interned string, together with its [`Symbol`].
+///
+/// It's returned by the `interned` method of the interners,
+/// and can be displayed as the string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interned<'a> {
+    symbol: Symbol,
+    string: &'a str,
+}
+
+/* Symbol */
+
+impl Symbol {
+    /// Returns a new symbol from its `index`.
+    ///
+    /// Returns `None` if `index` >= [`u32::MAX`].
+    #[inline]
+    pub const fn from_index(index: usize) -> Option<Symbol> {
+        if index < u32::MAX as usize {
+            // the value is never 0
+            match NonZeroU32::new(index as u32 + 1) {
+                Some(n) => Some(Symbol(n)),
+                None => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the symbol, in insertion order.
+    #[inline]
+    pub const fn index(self) -> usize {
+        self.0.get() as usize - 1
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Symbol({})", self.index())
+    }
+}
+
+/* Interner */
+
+#[cfg(feature = "alloc")]
+impl Interner {
+    /// Creates a new empty interner.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            arena: String::new(),
+            ends: Vec::new(),
+            sorted: Vec::new(),
+        }
+    }
+
+    /// Returns the number of interned strings.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if there are no interned strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Removes all the interned strings.
+    ///
+    /// All the previously returned symbols become invalid.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.arena.clear();
+        self.ends.clear();
+        self.sorted.clear();
+    }
+
+    /// Interns the `string`, and returns its symbol.
+    ///
+    /// # Panics
+    /// Panics if the number of interned strings would reach [`u32::MAX`].
+    pub fn intern(&mut self, string: &str) -> Symbol {
+        match self.search(string) {
+            Ok(i) => self.sorted[i],
+            Err(i) => {
+                let symbol = Symbol::from_index(self.len()).expect("too many symbols");
+                self.arena.push_str(string);
+                self.ends.push(self.arena.len());
+                self.sorted.insert(i, symbol);
+                symbol
+            }
+        }
+    }
+
+    /// Returns the symbol of the `string`, if it has been interned.
+    #[inline]
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        self.search(string).ok().map(|i| self.sorted[i])
+    }
+
+    /// Returns the string of the `symbol`.
+    ///
+    /// Returns `None` if the symbol doesn't belong to this interner.
+    #[inline]
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        let end = *self.ends.get(symbol.index())?;
+        let start = symbol.index().checked_sub(1).map_or(0, |i| self.ends[i]);
+        Some(&self.arena[start..end])
+    }
+
+    /// Returns the interned string of the `symbol`, which can be displayed.
+    ///
+    /// Returns `None` if the symbol doesn't belong to this interner.
+    #[inline]
+    pub fn interned(&self, symbol: Symbol) -> Option<Interned<'_>> {
+        self.resolve(symbol)
+            .map(|string| Interned { symbol, string })
+    }
+
+    /// Returns an iterator over the interned strings, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = Interned<'_>> {
+        (0..self.len()).filter_map(|i| self.interned(Symbol::from_index(i)?))
+    }
+
+    /// Returns the position of the `string` in the sorted symbols.
+    fn search(&self, string: &str) -> core::result::Result<usize, usize> {
+        self.sorted
+            .binary_search_by(|s| self.resolve(*s).unwrap_or_default().cmp(string))
+    }
+}
+
+/* StaticInterner */
+
+impl<const CAP: usize, const N: usize> StaticInterner<CAP, N> {
+    /// Creates a new empty static interner.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            arena: StaticU8String::new(),
+            ends: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of interned strings.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no interned strings.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the remaining capacity of the arena, in bytes.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        self.arena.remaining_capacity()
+    }
+
+    /// Removes all the interned strings.
+    ///
+    /// All the previously returned symbols become invalid.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.arena.clear();
+        self.len = 0;
+    }
+
+    /// Interns the `string`, and returns its symbol.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][Error::NotEnoughCapacity] if the string
+    /// doesn't fit in the arena, or if there are already `N` strings.
+    pub fn intern(&mut self, string: &str) -> Result<Symbol> {
+        if let Some(symbol) = self.get(string) {
+            return Ok(symbol);
+        }
+        if self.len == N {
+            return Err(Error::NotEnoughCapacity(N + 1));
+        }
+        let symbol = Symbol::from_index(self.len).ok_or(Error::OutOfBounds)?;
+        self.arena.try_push_str_complete(string)?;
+        self.ends[self.len] = self.arena.len() as u8;
+        self.len += 1;
+        Ok(symbol)
+    }
+
+    /// Returns the symbol of the `string`, if it has been interned.
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        (0..self.len)
+            .find(|i| self.str_at(*i) == string)
+            .and_then(Symbol::from_index)
+    }
+
+    /// Returns the string of the `symbol`.
+    ///
+    /// Returns `None` if the symbol doesn't belong to this interner.
+    #[inline]
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        (symbol.index() < self.len).then(|| self.str_at(symbol.index()))
+    }
+
+    /// Returns the interned string of the `symbol`, which can be displayed.
+    ///
+    /// Returns `None` if the symbol doesn't belong to this interner.
+    #[inline]
+    pub fn interned(&self, symbol: Symbol) -> Option<Interned<'_>> {
+        self.resolve(symbol)
+            .map(|string| Interned { symbol, string })
+    }
+
+    /// Returns an iterator over the interned strings, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = Interned<'_>> {
+        (0..self.len).filter_map(|i| self.interned(Symbol::from_index(i)?))
+    }
+
+    /// Returns the string at `index`, which must be < `len`.
+    fn str_at(&self, index: usize) -> &str {
+        let start = index.checked_sub(1).map_or(0, |i| self.ends[i]);
+        &self.arena.as_str()[start as usize..self.ends[index] as usize]
+    }
+}
+
+impl<const CAP: usize, const N: usize> Default for StaticInterner<CAP, N> {
+    /// Returns an empty static interner.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAP: usize, const N: usize> fmt::Debug for StaticInterner<CAP, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|i| i.string))
+            .finish()
+    }
+}
+
+/* Interned */
+
+impl<'a> Interned<'a> {
+    /// Returns the symbol.
+    #[inline]
+    pub const fn symbol(&self) -> Symbol {
+        self.symbol
+    }
+
+    /// Returns the string slice.
+    #[inline]
+    pub const fn as_str(&self) -> &'a str {
+        self.string
+    }
+}
+
+impl Textual for Interned<'_> {}
+
+impl fmt::Display for Interned<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.string)
+    }
+}
+
+/* serde impls */
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+    use serde::{
+        de::{self, DeserializeSeed, SeqAccess, Visitor},
+        ser::SerializeSeq,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    impl Serialize for Symbol {
+        fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.index() as u32)
+        }
+    }
+    impl<'de> Deserialize<'de> for Symbol {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            let index = u32::deserialize(deserializer)?;
+            Symbol::from_index(index as usize).ok_or_else(|| de::Error::custom("invalid symbol"))
+        }
+    }
+
+    /// Serializes the strings of a symbol table as a sequence, in symbol order.
+    fn serialize_table<'a, S: Serializer>(
+        serializer: S,
+        len: usize,
+        strings: impl Iterator<Item = Interned<'a>>,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        for interned in strings {
+            seq.serialize_element(interned.as_str())?;
+        }
+        seq.end()
+    }
+
+    /// A symbol table that can be deserialized from a sequence of strings.
+    ///
+    /// A repeated string is an error, since it would shift the symbols after it.
+    trait InternStr: Sized {
+        fn intern_str(&mut self, string: &str) -> Result<()>;
+    }
+    #[cfg(feature = "alloc")]
+    impl InternStr for Interner {
+        fn intern_str(&mut self, string: &str) -> Result<()> {
+            if self.get(string).is_some() {
+                return Err(Error::Other("repeated string in a symbol table"));
+            }
+            self.intern(string);
+            Ok(())
+        }
+    }
+    impl<const CAP: usize, const N: usize> InternStr for StaticInterner<CAP, N> {
+        fn intern_str(&mut self, string: &str) -> Result<()> {
+            if self.get(string).is_some() {
+                return Err(Error::Other("repeated string in a symbol table"));
+            }
+            self.intern(string).map(|_| ())
+        }
+    }
+
+    /// Interns each deserialized string into the table, without owning it.
+    struct InternSeed<'t, T>(&'t mut T);
+
+    impl<'de, T: InternStr> DeserializeSeed<'de> for InternSeed<'_, T> {
+        type Value = ();
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> core::result::Result<(), D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+    impl<'de, T: InternStr> Visitor<'de> for InternSeed<'_, T> {
+        type Value = ();
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a string")
+        }
+        fn visit_str<E: de::Error>(self, string: &str) -> core::result::Result<(), E> {
+            self.0.intern_str(string).map_err(E::custom)
+        }
+    }
+
+    /// Deserializes a sequence of strings into a symbol table.
+    struct TableVisitor<T>(T);
+
+    impl<'de, T: InternStr> Visitor<'de> for TableVisitor<T> {
+        type Value = T;
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a sequence of strings")
+        }
+        fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> core::result::Result<T, A::Error> {
+            while seq.next_element_seed(InternSeed(&mut self.0))?.is_some() {}
+            Ok(self.0)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Serialize for Interner {
+        fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+            serialize_table(serializer, self.len(), self.iter())
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'de> Deserialize<'de> for Interner {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            deserializer.deserialize_seq(TableVisitor(Interner::new()))
+        }
+    }
+
+    impl<const CAP: usize, const N: usize> Serialize for StaticInterner<CAP, N> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+            serialize_table(serializer, self.len(), self.iter())
+        }
+    }
+    impl<'de, const CAP: usize, const N: usize> Deserialize<'de> for StaticInterner<CAP, N> {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            deserializer.deserialize_seq(TableVisitor(StaticInterner::new()))
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interners_agree() {
+        let words = ["fn", "let", "", "mut", "let", "ñandú", "fn", "", "impl"];
+        let mut heap = Interner::new();
+        let mut stat = StaticInterner::<32, 6>::new();
+
+        for word in words {
+            let (h, s) = (heap.intern(word), stat.intern(word).unwrap());
+            assert_eq![h, s];
+            assert_eq![Some(word), heap.resolve(h)];
+            assert_eq![Some(word), stat.resolve(s)];
+        }
+        assert_eq![6, heap.len()];
+        assert_eq![6, stat.len()];
+        assert![heap.iter().eq(stat.iter())];
+        assert_eq![Some(3), heap.get("mut").map(Symbol::index)];
+        assert_eq![None, stat.get("struct")];
+        assert![stat.intern("struct").is_err()];
+        assert_eq![None, heap.resolve(Symbol::from_index(6).unwrap())];
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod serde_tests {
+    use super::*;
+    use serde::{
+        de::value::{Error as ValueError, SeqDeserializer},
+        Deserialize,
+    };
+    use serde_test::{assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token};
+
+    /// Deserializes a symbol table from a sequence of strings.
+    fn from_strings<'de, T: Deserialize<'de>>(
+        strings: &[&'de str],
+    ) -> core::result::Result<T, ValueError> {
+        T::deserialize(SeqDeserializer::new(strings.iter().copied()))
+    }
+
+    #[test]
+    fn symbol() {
+        assert_tokens(&Symbol::from_index(7).unwrap(), &[Token::U32(7)]);
+        assert_de_tokens_error::<Symbol>(&[Token::U32(u32::MAX)], "invalid symbol");
+    }
+
+    #[test]
+    fn static_interner() {
+        let mut table = StaticInterner::<16, 4>::new();
+        let symbols = ["b", "a"].map(|s| table.intern(s).unwrap());
+        assert_ser_tokens(
+            &table,
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str("b"),
+                Token::Str("a"),
+                Token::SeqEnd,
+            ],
+        );
+
+        let restored: StaticInterner<16, 4> = from_strings(&["b", "a"]).unwrap();
+        assert![restored.iter().eq(table.iter())];
+        assert_eq![Some("a"), restored.resolve(symbols[1])];
+
+        assert_de_tokens_error::<StaticInterner<16, 4>>(
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str("a"),
+                Token::Str("a"),
+            ],
+            "repeated string in a symbol table",
+        );
+        assert_de_tokens_error::<StaticInterner<16, 1>>(
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str("a"),
+                Token::Str("b"),
+            ],
+            "Not enough capacity. Needed: 2",
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn interner() {
+        let mut table = Interner::new();
+        let symbols = ["let", "fn", "ñandú"].map(|s| table.intern(s));
+        assert_ser_tokens(
+            &table,
+            &[
+                Token::Seq { len: Some(3) },
+                Token::Str("let"),
+                Token::Str("fn"),
+                Token::Str("ñandú"),
+                Token::SeqEnd,
+            ],
+        );
+
+        let restored: Interner = from_strings(&["let", "fn", "ñandú"]).unwrap();
+        for (symbol, string) in symbols.iter().zip(["let", "fn", "ñandú"]) {
+            assert_eq![Some(string), restored.resolve(*symbol)];
+            assert_eq![Some(*symbol), restored.get(string)];
+        }
+
+        assert_de_tokens_error::<Interner>(
+            &[Token::Seq { len: None }, Token::Str("fn"), Token::Str("fn")],
+            "repeated string in a symbol table",
+        );
+    }
+}
@@ -4,15 +4,28 @@
 //
 
 mod counter;
+mod interner;
 mod non_nul;
+mod pattern;
+#[cfg(feature = "alloc")]
+mod rope;
+#[cfg(feature = "alloc")]
+mod small;
 mod u8string;
 
+pub(crate) use pattern::impl_str_search;
+
 pub use all::*;
 pub(crate) mod all {
     #[doc(inline)]
-    pub use super::{non_nul::*, u8string::*};
+    pub use super::{
+        interner::{Interned, StaticInterner, Symbol},
+        non_nul::*,
+        pattern::StrPattern,
+        u8string::*,
+    };
 
     #[doc(inline)]
     #[cfg(feature = "alloc")]
-    pub use super::counter::counter_string;
+    pub use super::{counter::counter_string, interner::Interner, rope::*, small::SmallString};
 }
@@ -12,10 +12,14 @@ use alloc::{ffi::CString, str::Chars, string::ToString};
 
 use crate::{
     error::{TextosError as Error, TextosResult as Result},
-    macros::impl_sized_alias,
-    unicode::char::*,
+    macros::{impl_cmp_str, impl_sized_alias},
+    textual::Textual,
+    unicode::{char::*, string::impl_str_search},
+};
+use core::{
+    ffi::{c_char, CStr},
+    fmt,
 };
-use core::fmt;
 use devela::codegen::paste;
 
 /* definitions */
@@ -27,7 +31,15 @@ const NUL: char = '\0';
 /// Can't contain nul chars.
 ///
 /// Internally, the first 0 byte in the array indicates the end of the string.
-#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// It's guaranteed to have the same layout as `[u8; CAP]`, so it can be used
+/// in place of a fixed-size `[c_char; CAP]` array in C structs.
+/// See [`as_c_str`][Self#method.as_c_str] and [`from_c_str`][Self#method.from_c_str].
+///
+/// Comparisons and hashing are based on the string contents, so strings with
+/// different capacities can be compared between them, and with [`str`] slices.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct StaticNonNulString<const CAP: usize> {
     arr: [u8; CAP],
 }
@@ -58,6 +70,76 @@ impl<const CAP: usize> StaticNonNulString<CAP> {
         Self { arr: [0; CAP] }
     }
 
+    /// Creates a new `StaticNonNulString` from a C string slice.
+    ///
+    /// The capacity must be enough to hold the bytes of the string
+    /// plus the terminating nul byte.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][Error::NotEnoughCapacity] with the needed
+    /// capacity if `CAP` is not greater than the length of the string,
+    /// or [`Utf8`][Error::Utf8] if it's not valid UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::ffi::CStr;
+    /// use textos::unicode::string::NonNulString64;
+    ///
+    /// let c = CStr::from_bytes_with_nul(b"hello\0").unwrap();
+    /// let s = NonNulString64::from_c_str(c).unwrap();
+    /// assert_eq![s.as_str(), "hello"];
+    /// assert_eq![s.as_c_str().unwrap(), c];
+    ///
+    /// let c = CStr::from_bytes_with_nul(b"12345678\0").unwrap();
+    /// assert![NonNulString64::from_c_str(c).is_err()];
+    /// ```
+    pub fn from_c_str(c_str: &CStr) -> Result<Self> {
+        let bytes = c_str.to_bytes();
+        if bytes.len() >= CAP {
+            return Err(Error::NotEnoughCapacity(bytes.len() + 1));
+        }
+        let _ = core::str::from_utf8(bytes)?;
+
+        let mut new = Self::new();
+        new.arr[..bytes.len()].copy_from_slice(bytes);
+        Ok(new)
+    }
+
+    /// Creates a new `StaticNonNulString` from a string slice,
+    /// without dropping any nul characters.
+    ///
+    /// # Errors
+    /// Returns [`NulCharacter`][Error::NulCharacter] with the byte position
+    /// of the first nul character, or [`NotEnoughCapacity`][Error::NotEnoughCapacity]
+    /// if the string doesn't fit in `CAP` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::{error::TextosError, unicode::string::StaticNonNulString};
+    ///
+    /// let s = StaticNonNulString::<4>::try_from_str("abc").unwrap();
+    /// assert_eq![s, "abc"];
+    /// assert_eq![
+    ///     StaticNonNulString::<4>::try_from_str("a\0c"),
+    ///     Err(TextosError::NulCharacter { position: 1 })
+    /// ];
+    /// assert_eq![
+    ///     StaticNonNulString::<4>::try_from_str("abcde"),
+    ///     Err(TextosError::NotEnoughCapacity(5))
+    /// ];
+    /// ```
+    pub fn try_from_str(string: &str) -> Result<Self> {
+        if let Some(position) = string.bytes().position(|b| b == 0) {
+            Err(Error::NulCharacter { position })
+        } else if string.len() > CAP {
+            Err(Error::NotEnoughCapacity(string.len()))
+        } else {
+            let mut new = Self::new();
+            new.arr[..string.len()].copy_from_slice(string.as_bytes());
+            Ok(new)
+        }
+    }
+
     /// Creates a new `StaticNonNulString` from a `Char7`.
     ///
     /// If `c`.[`is_nul()`][Char7#method.is_nul] an empty string will be returned.
@@ -249,6 +331,9 @@ impl<const CAP: usize> StaticNonNulString<CAP> {
     }
 
     /// Returns a mutable byte slice of the inner string slice.
+    ///
+    /// # Safety
+    /// The content must be valid UTF-8.
     #[inline]
     #[cfg(feature = "unsafe")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe")))]
@@ -283,11 +368,60 @@ impl<const CAP: usize> StaticNonNulString<CAP> {
         // SAFETY
         #[cfg(feature = "unsafe")]
         unsafe {
-            return core::str::from_utf8_unchecked(self.arr.get_unchecked(0..self.len()));
+            core::str::from_utf8_unchecked(self.arr.get_unchecked(0..self.len()))
         }
     }
 
+    /// Returns the inner C string slice, without allocating.
+    ///
+    /// The string must have at least one unused byte of capacity left,
+    /// in order to be used as the terminating nul byte.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][Error::NotEnoughCapacity] with the needed
+    /// capacity, `CAP + 1`, if the string [`is_full`][Self#method.is_full].
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::{error::TextosError, unicode::string::StaticNonNulString};
+    ///
+    /// let s = StaticNonNulString::<4>::try_from_str("abc").unwrap();
+    /// assert_eq![s.as_c_str().unwrap().to_bytes(), b"abc"];
+    ///
+    /// let full = StaticNonNulString::<3>::try_from_str("abc").unwrap();
+    /// assert_eq![full.as_c_str(), Err(TextosError::NotEnoughCapacity(4))];
+    /// ```
+    #[inline]
+    pub fn as_c_str(&self) -> Result<&CStr> {
+        #[cfg(not(feature = "unsafe"))]
+        return CStr::from_bytes_until_nul(&self.arr)
+            .map_err(|_| Error::NotEnoughCapacity(CAP + 1));
+
+        // SAFETY: the first 0 byte in the array is the end of the string
+        #[cfg(feature = "unsafe")]
+        {
+            let len = self.len();
+            if len < CAP {
+                Ok(unsafe { CStr::from_bytes_with_nul_unchecked(self.arr.get_unchecked(..=len)) })
+            } else {
+                Err(Error::NotEnoughCapacity(CAP + 1))
+            }
+        }
+    }
+
+    /// Returns a raw pointer to the start of the inner array.
+    ///
+    /// The pointed string is only nul-terminated if it's not
+    /// [`is_full`][Self#method.is_full].
+    #[inline]
+    pub const fn as_ptr(&self) -> *const c_char {
+        self.arr.as_ptr() as *const c_char
+    }
+
     /// Returns the mutable inner string slice.
+    ///
+    /// # Safety
+    /// The content must be valid UTF-8.
     #[cfg(feature = "unsafe")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe")))]
     pub unsafe fn as_str_mut(&mut self) -> &mut str {
@@ -297,7 +431,7 @@ impl<const CAP: usize> StaticNonNulString<CAP> {
     /// Returns an iterator over the `chars` of this grapheme cluster.
     #[cfg(feature = "alloc")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
-    pub fn chars(&self) -> Chars {
+    pub fn chars(&self) -> Chars<'_> {
         self.as_str().chars()
     }
 
@@ -465,8 +599,14 @@ impl<const CAP: usize> StaticNonNulString<CAP> {
     }
 }
 
+impl_str_search![StaticNonNulString => StaticNonNulString];
+
 /* traits */
 
+impl_cmp_str![StaticNonNulString];
+
+impl<const CAP: usize> Textual for StaticNonNulString<CAP> {}
+
 impl<const CAP: usize> Default for StaticNonNulString<CAP> {
     /// Returns an empty string.
     #[inline]
@@ -481,6 +621,16 @@ impl<const CAP: usize> fmt::Display for StaticNonNulString<CAP> {
         write!(f, "{}", self.as_str())
     }
 }
+impl<const CAP: usize> fmt::Write for StaticNonNulString<CAP> {
+    /// Appends the complete string slice, or fails if it doesn't fit.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str_complete(s)
+            .map(|_| ())
+            .map_err(|_| fmt::Error)
+    }
+}
+
 impl<const CAP: usize> fmt::Debug for StaticNonNulString<CAP> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
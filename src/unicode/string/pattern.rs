@@ -0,0 +1,512 @@
+// textos::unicode::string::pattern
+//
+//! String patterns.
+//
+// TOC
+// - trait definition
+// - trait impls
+// - search methods macro
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::str::{MatchIndices, Split, SplitN};
+
+/* definition */
+
+/// A string pattern, usable for searching and splitting string types.
+///
+/// It mirrors the unstable [`Pattern`][core::str::pattern::Pattern] trait,
+/// and it's implemented for the same types:
+/// [`char`], [`&str`][str], [`&&str`][str], [`&String`][String],
+/// [`&[char]`][slice], [`[char; N]`][array] and [`FnMut(char) -> bool`][FnMut].
+///
+/// # Examples
+/// ```
+/// use textos::unicode::string::String256;
+///
+/// let s = String256::from_char('a');
+/// assert![s.contains('a')];
+/// assert![s.contains(|c: char| c.is_ascii_lowercase())];
+/// assert![s.contains(['b', 'a'])];
+/// assert![!s.contains("b")];
+/// ```
+pub trait StrPattern: Sized {
+    /// The iterator returned by [`split_in`][Self::split_in].
+    type Split<'a>: Iterator<Item = &'a str>;
+
+    /// The iterator returned by [`splitn_in`][Self::splitn_in].
+    type SplitN<'a>: Iterator<Item = &'a str>;
+
+    /// The iterator returned by [`match_indices_in`][Self::match_indices_in].
+    type MatchIndices<'a>: Iterator<Item = (usize, &'a str)>;
+
+    /// Returns the byte index of the first match of this pattern in `string`.
+    fn find_in(self, string: &str) -> Option<usize>;
+
+    /// Returns the byte index of the first byte of the last match of this
+    /// pattern in `string`.
+    fn rfind_in(self, string: &str) -> Option<usize>;
+
+    /// Returns `true` if this pattern matches a sub-slice of `string`.
+    fn is_contained_in(self, string: &str) -> bool;
+
+    /// Returns `true` if this pattern matches a prefix of `string`.
+    fn is_prefix_of(self, string: &str) -> bool;
+
+    /// Returns `true` if this pattern matches a suffix of `string`.
+    fn is_suffix_of(self, string: &str) -> bool;
+
+    /// Returns `string` with all the prefixes and suffixes that match this
+    /// pattern repeatedly removed.
+    fn trim_in(self, string: &str) -> &str;
+
+    /// Returns `string` with all the prefixes that match this pattern
+    /// repeatedly removed.
+    fn trim_start_in(self, string: &str) -> &str;
+
+    /// Returns `string` with all the suffixes that match this pattern
+    /// repeatedly removed.
+    fn trim_end_in(self, string: &str) -> &str;
+
+    /// Returns an iterator over the sub-slices of `string`,
+    /// separated by the matches of this pattern.
+    fn split_in(self, string: &str) -> Self::Split<'_>;
+
+    /// Returns an iterator over the sub-slices of `string`,
+    /// separated by the matches of this pattern, restricted to returning
+    /// at most `n` items.
+    fn splitn_in(self, n: usize, string: &str) -> Self::SplitN<'_>;
+
+    /// Returns an iterator over the disjoint matches of this pattern in `string`,
+    /// as well as the byte index that the match starts at.
+    fn match_indices_in(self, string: &str) -> Self::MatchIndices<'_>;
+}
+
+/* impls */
+
+macro_rules! impl_str_pattern {
+    // $p: the pattern type.
+    // $both_ends: optional method returning an equivalent pattern that can
+    //   search from both ends, for the pattern types that can't in the MSRV.
+    // $lt: optional lifetimes and generics needed by the pattern type.
+    ($p:ty $(=> $both_ends:ident)? $(, $lt:lifetime)? $(; $($gen:tt)+)?) => {
+        impl<$($lt,)? $($($gen)+)?> StrPattern for $p {
+            type Split<'a> = Split<'a, $p>;
+            type SplitN<'a> = SplitN<'a, $p>;
+            type MatchIndices<'a> = MatchIndices<'a, $p>;
+
+            #[inline]
+            fn find_in(self, string: &str) -> Option<usize> {
+                string.find(self)
+            }
+            #[inline]
+            fn rfind_in(self, string: &str) -> Option<usize> {
+                string.rfind(self)
+            }
+            #[inline]
+            fn is_contained_in(self, string: &str) -> bool {
+                string.contains(self)
+            }
+            #[inline]
+            fn is_prefix_of(self, string: &str) -> bool {
+                string.starts_with(self)
+            }
+            #[inline]
+            fn is_suffix_of(self, string: &str) -> bool {
+                string.ends_with(self)
+            }
+            #[inline]
+            fn trim_in(self, string: &str) -> &str {
+                string.trim_matches(self$(.$both_ends())?)
+            }
+            #[inline]
+            fn trim_start_in(self, string: &str) -> &str {
+                string.trim_start_matches(self)
+            }
+            #[inline]
+            fn trim_end_in(self, string: &str) -> &str {
+                string.trim_end_matches(self$(.$both_ends())?)
+            }
+            #[inline]
+            fn split_in(self, string: &str) -> Self::Split<'_> {
+                string.split(self)
+            }
+            #[inline]
+            fn splitn_in(self, n: usize, string: &str) -> Self::SplitN<'_> {
+                string.splitn(n, self)
+            }
+            #[inline]
+            fn match_indices_in(self, string: &str) -> Self::MatchIndices<'_> {
+                string.match_indices(self)
+            }
+        }
+    };
+}
+impl_str_pattern![char];
+impl_str_pattern![&'p [char], 'p];
+impl_str_pattern![[char; N] => as_slice; const N: usize];
+impl_str_pattern![F; F: FnMut(char) -> bool];
+
+impl<'p> StrPattern for &'p str {
+    type Split<'a> = Split<'a, &'p str>;
+    type SplitN<'a> = SplitN<'a, &'p str>;
+    type MatchIndices<'a> = MatchIndices<'a, &'p str>;
+
+    #[inline]
+    fn find_in(self, string: &str) -> Option<usize> {
+        string.find(self)
+    }
+    #[inline]
+    fn rfind_in(self, string: &str) -> Option<usize> {
+        string.rfind(self)
+    }
+    #[inline]
+    fn is_contained_in(self, string: &str) -> bool {
+        string.contains(self)
+    }
+    #[inline]
+    fn is_prefix_of(self, string: &str) -> bool {
+        string.starts_with(self)
+    }
+    #[inline]
+    fn is_suffix_of(self, string: &str) -> bool {
+        string.ends_with(self)
+    }
+    /// A string slice pattern can't search from both ends at the same time,
+    /// so the prefixes are trimmed before the suffixes.
+    #[inline]
+    fn trim_in(self, string: &str) -> &str {
+        string.trim_start_matches(self).trim_end_matches(self)
+    }
+    #[inline]
+    fn trim_start_in(self, string: &str) -> &str {
+        string.trim_start_matches(self)
+    }
+    #[inline]
+    fn trim_end_in(self, string: &str) -> &str {
+        string.trim_end_matches(self)
+    }
+    #[inline]
+    fn split_in(self, string: &str) -> Self::Split<'_> {
+        string.split(self)
+    }
+    #[inline]
+    fn splitn_in(self, n: usize, string: &str) -> Self::SplitN<'_> {
+        string.splitn(n, self)
+    }
+    #[inline]
+    fn match_indices_in(self, string: &str) -> Self::MatchIndices<'_> {
+        string.match_indices(self)
+    }
+}
+
+// the references to string slices search like the string slice they point to.
+macro_rules! impl_str_pattern_ref {
+    // $p: the pattern type.
+    // $lt: the lifetimes needed by the pattern type, the first one of the slice.
+    // $to_str: a closure converting the pattern to the `&$slt str` slice.
+    ($p:ty; $slt:lifetime $(, $lt:lifetime)*; $to_str:expr) => {
+        impl<$slt $(, $lt)*> StrPattern for $p {
+            type Split<'a> = Split<'a, &$slt str>;
+            type SplitN<'a> = SplitN<'a, &$slt str>;
+            type MatchIndices<'a> = MatchIndices<'a, &$slt str>;
+
+            #[inline]
+            fn find_in(self, string: &str) -> Option<usize> {
+                $to_str(self).find_in(string)
+            }
+            #[inline]
+            fn rfind_in(self, string: &str) -> Option<usize> {
+                $to_str(self).rfind_in(string)
+            }
+            #[inline]
+            fn is_contained_in(self, string: &str) -> bool {
+                $to_str(self).is_contained_in(string)
+            }
+            #[inline]
+            fn is_prefix_of(self, string: &str) -> bool {
+                $to_str(self).is_prefix_of(string)
+            }
+            #[inline]
+            fn is_suffix_of(self, string: &str) -> bool {
+                $to_str(self).is_suffix_of(string)
+            }
+            #[inline]
+            fn trim_in(self, string: &str) -> &str {
+                $to_str(self).trim_in(string)
+            }
+            #[inline]
+            fn trim_start_in(self, string: &str) -> &str {
+                $to_str(self).trim_start_in(string)
+            }
+            #[inline]
+            fn trim_end_in(self, string: &str) -> &str {
+                $to_str(self).trim_end_in(string)
+            }
+            #[inline]
+            fn split_in(self, string: &str) -> Self::Split<'_> {
+                $to_str(self).split_in(string)
+            }
+            #[inline]
+            fn splitn_in(self, n: usize, string: &str) -> Self::SplitN<'_> {
+                $to_str(self).splitn_in(n, string)
+            }
+            #[inline]
+            fn match_indices_in(self, string: &str) -> Self::MatchIndices<'_> {
+                $to_str(self).match_indices_in(string)
+            }
+        }
+    };
+}
+impl_str_pattern_ref![&'q &'p str; 'p, 'q; |s: &&'p str| *s];
+#[cfg(feature = "alloc")]
+impl_str_pattern_ref![&'p String; 'p; String::as_str];
+
+/* methods */
+
+/// implements the search and transform methods for string types.
+///
+/// The type must have an `as_str` method.
+macro_rules! impl_str_search {
+    // $type: the name of the type, with a `CAP` const generic argument.
+    // $out: the string type returned by the transform methods, which must
+    //   have the `new` and `try_push_str_complete` methods.
+    ($type:ident => $out:ident) => {
+        impl<const CAP: usize> $type<CAP> {
+            $crate::unicode::string::impl_str_search![@search];
+
+            /// Returns a new string with all the matches of the pattern
+            /// replaced with the `to` string slice.
+            ///
+            /// # Errors
+            /// Returns an error if the result doesn't fit in the capacity.
+            #[inline]
+            pub fn replace<P: $crate::unicode::string::StrPattern>(
+                &self,
+                from: P,
+                to: &str,
+            ) -> $crate::error::TextosResult<$out<CAP>> {
+                self.replacen(from, to, usize::MAX)
+            }
+
+            /// Returns a new string with the first `count` matches of the pattern
+            /// replaced with the `to` string slice.
+            ///
+            /// # Errors
+            /// Returns an error if the result doesn't fit in the capacity.
+            pub fn replacen<P: $crate::unicode::string::StrPattern>(
+                &self,
+                from: P,
+                to: &str,
+                count: usize,
+            ) -> $crate::error::TextosResult<$out<CAP>> {
+                let string = self.as_str();
+                let mut new = $out::<CAP>::new();
+                let mut last_end = 0;
+                for (start, part) in from.match_indices_in(string).take(count) {
+                    new.try_push_str_complete(&string[last_end..start])?;
+                    new.try_push_str_complete(to)?;
+                    last_end = start + part.len();
+                }
+                new.try_push_str_complete(&string[last_end..])?;
+                Ok(new)
+            }
+        }
+    };
+
+    // the methods that don't depend on the output type.
+    (@search) => {
+        /// Returns the byte index of the first match of the pattern.
+        ///
+        /// See [`str::find`].
+        #[inline]
+        pub fn find<P: $crate::unicode::string::StrPattern>(&self, pat: P) -> Option<usize> {
+            pat.find_in(self.as_str())
+        }
+
+        /// Returns the byte index of the first byte of the last match of the pattern.
+        ///
+        /// See [`str::rfind`].
+        #[inline]
+        pub fn rfind<P: $crate::unicode::string::StrPattern>(&self, pat: P) -> Option<usize> {
+            pat.rfind_in(self.as_str())
+        }
+
+        /// Returns `true` if the pattern matches a sub-slice.
+        ///
+        /// See [`str::contains`].
+        #[inline]
+        pub fn contains<P: $crate::unicode::string::StrPattern>(&self, pat: P) -> bool {
+            pat.is_contained_in(self.as_str())
+        }
+
+        /// Returns `true` if the pattern matches a prefix.
+        ///
+        /// See [`str::starts_with`].
+        #[inline]
+        pub fn starts_with<P: $crate::unicode::string::StrPattern>(&self, pat: P) -> bool {
+            pat.is_prefix_of(self.as_str())
+        }
+
+        /// Returns `true` if the pattern matches a suffix.
+        ///
+        /// See [`str::ends_with`].
+        #[inline]
+        pub fn ends_with<P: $crate::unicode::string::StrPattern>(&self, pat: P) -> bool {
+            pat.is_suffix_of(self.as_str())
+        }
+
+        /// Returns an iterator over the sub-slices separated by the pattern.
+        ///
+        /// See [`str::split`].
+        #[inline]
+        pub fn split<P: $crate::unicode::string::StrPattern>(&self, pat: P) -> P::Split<'_> {
+            pat.split_in(self.as_str())
+        }
+
+        /// Returns an iterator over at most `n` sub-slices separated by the pattern.
+        ///
+        /// See [`str::splitn`].
+        #[inline]
+        pub fn splitn<P: $crate::unicode::string::StrPattern>(&self, n: usize, pat: P) -> P::SplitN<'_> {
+            pat.splitn_in(n, self.as_str())
+        }
+
+        /// Returns an iterator over the sub-slices separated by any amount of whitespace.
+        ///
+        /// See [`str::split_whitespace`].
+        #[inline]
+        pub fn split_whitespace(&self) -> core::str::SplitWhitespace<'_> {
+            self.as_str().split_whitespace()
+        }
+
+        /// Returns an iterator over the lines, as sub-slices.
+        ///
+        /// See [`str::lines`].
+        #[inline]
+        pub fn lines(&self) -> core::str::Lines<'_> {
+            self.as_str().lines()
+        }
+
+        /// Returns a sub-slice with leading and trailing whitespace removed.
+        ///
+        /// See [`str::trim`].
+        #[inline]
+        pub fn trim(&self) -> &str {
+            self.as_str().trim()
+        }
+
+        /// Returns a sub-slice with leading whitespace removed.
+        ///
+        /// See [`str::trim_start`].
+        #[inline]
+        pub fn trim_start(&self) -> &str {
+            self.as_str().trim_start()
+        }
+
+        /// Returns a sub-slice with trailing whitespace removed.
+        ///
+        /// See [`str::trim_end`].
+        #[inline]
+        pub fn trim_end(&self) -> &str {
+            self.as_str().trim_end()
+        }
+
+        /// Returns a sub-slice with all the prefixes and suffixes that match
+        /// the pattern repeatedly removed.
+        ///
+        /// See [`str::trim_matches`].
+        #[inline]
+        pub fn trim_matches<P: $crate::unicode::string::StrPattern>(&self, pat: P) -> &str {
+            pat.trim_in(self.as_str())
+        }
+
+        /// Returns a sub-slice with all the prefixes that match
+        /// the pattern repeatedly removed.
+        ///
+        /// See [`str::trim_start_matches`].
+        #[inline]
+        pub fn trim_start_matches<P: $crate::unicode::string::StrPattern>(&self, pat: P) -> &str {
+            pat.trim_start_in(self.as_str())
+        }
+
+        /// Returns a sub-slice with all the suffixes that match
+        /// the pattern repeatedly removed.
+        ///
+        /// See [`str::trim_end_matches`].
+        #[inline]
+        pub fn trim_end_matches<P: $crate::unicode::string::StrPattern>(&self, pat: P) -> &str {
+            pat.trim_end_in(self.as_str())
+        }
+    };
+}
+pub(crate) use impl_str_search;
+
+#[cfg(test)]
+mod tests {
+    use crate::unicode::{
+        egc::{StaticNonNulEgc, StaticU8Egc},
+        string::StaticNonNulString,
+    };
+
+    #[test]
+    fn non_nul_string() {
+        let mut s = StaticNonNulString::<6>::new();
+        s.push_str("a,\0b");
+        assert_eq!["a,b", s.as_str()];
+
+        // the unused nul bytes are not part of the searched string
+        assert_eq![None, s.find('\0')];
+        assert_eq![Some(2), s.rfind(|c: char| c.is_alphabetic())];
+        assert![s.ends_with('b') && !s.contains("\0")];
+        assert![s.split(',').eq(["a", "b"])];
+        assert_eq!["a", s.trim_end_matches([',', 'b'])];
+
+        assert_eq!["a;;b", s.replace(',', ";;").unwrap()];
+        assert_eq!["ab", s.replacen(",", "", 1).unwrap()];
+        // the nul characters of the replacement are dropped
+        assert_eq!["ab", s.replace(',', "\0").unwrap()];
+        assert![s.replace(',', "12345").is_err()];
+    }
+
+    #[test]
+    fn egcs() {
+        let e = StaticU8Egc::<8>::try_from_str("e\u{301}").unwrap();
+        assert_eq!["e", e.replace('\u{301}', "").unwrap()];
+        assert_eq!["é!", e.replace("e\u{301}", "é!").unwrap()];
+        assert![e.split('\u{301}').eq(["e", ""])];
+        assert_eq!["\u{301}", e.trim_start_matches('e')];
+        assert![e.replace('e', "toolong").is_err()];
+
+        let space = StaticNonNulEgc::<4>::from_char(' ');
+        assert_eq!["", space.trim()];
+        assert_eq![None, space.find('\0')];
+        assert![space.split(' ').eq(["", ""])];
+        assert_eq!["\t\t", space.replace(' ', "\t\t").unwrap()];
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn string_egc() {
+        use crate::unicode::egc::StringEgc;
+        let e = StringEgc::from_char(' ');
+        assert_eq!["", e.trim()];
+        assert_eq!["__", e.replace(' ', "__")];
+        assert![e.split(' ').eq(["", ""])];
+    }
+
+    #[test]
+    fn str_references() {
+        let s = StaticNonNulString::<8>::try_from_str("a--b--c").unwrap();
+        let (dash, tail): (&&str, &&str) = (&"--", &"--b--c");
+        assert_eq![Some(1), s.find(dash)];
+        assert![s.split(dash).eq(["a", "b", "c"])];
+        assert_eq!["a", s.trim_end_matches(tail)];
+        #[cfg(feature = "alloc")]
+        {
+            let dash = alloc::string::String::from("--");
+            assert_eq![Some(4), s.rfind(&dash)];
+            assert_eq!["a+b+c", s.replace(&dash, "+").unwrap()];
+        }
+    }
+}
@@ -0,0 +1,1078 @@
+// textos::unicode::string::rope
+//
+//! A rope of text, for editing.
+//
+// TOC
+// - definitions
+// - public methods
+// - node
+// - iterators
+// - trait impls
+// - tests
+
+use crate::{
+    error::{TextosError as Error, TextosResult as Result},
+    textual::Textual,
+    unicode::{egc::StaticU8Egc, width::str_width},
+};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::{fmt, mem, ops::Range};
+use unicode_segmentation::{Graphemes, UnicodeSegmentation};
+
+/* definitions */
+
+/// The maximum length in bytes of a leaf, before it gets split.
+const LEAF_MAX: usize = 1024;
+
+/// The target length in bytes of the leaves resulting from a split.
+const LEAF_SPLIT: usize = LEAF_MAX / 2;
+
+/// A UTF-8-encoded rope of text, backed by a balanced tree of [`String`]s.
+///
+/// Insertions and removals take *O(log n)* time, whether they are indexed by
+/// byte, `char` or extended grapheme cluster. Lines can be indexed in the same
+/// time, and their display width can be measured for moving a cursor through
+/// wide characters.
+///
+/// The leaves of the tree are always split at grapheme cluster boundaries.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::string::Rope;
+///
+/// let mut r = Rope::from("hello\nworld");
+/// r.insert(5, ", 世界").unwrap();
+/// assert_eq!["hello, 世界\nworld", r.to_string()];
+///
+/// assert_eq![2, r.len_lines()];
+/// assert_eq![Some(11), r.line_width(0)];
+/// assert_eq![Some(10), r.column_to_byte(0, 9)];
+///
+/// r.remove_chars(5..9).unwrap();
+/// assert_eq!["hello\nworld", r.to_string()];
+/// ```
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct Rope {
+    root: Node,
+}
+
+impl Rope {
+    /// Creates a new empty rope.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            root: Node::Leaf(String::new(), Metrics::ZERO),
+        }
+    }
+
+    //
+
+    /// Returns the length in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.root.metrics().bytes
+    }
+
+    /// Returns `true` if the current length is 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of `char`s.
+    #[inline]
+    pub fn len_chars(&self) -> usize {
+        self.root.metrics().chars
+    }
+
+    /// Returns the number of extended grapheme clusters.
+    #[inline]
+    pub fn len_egcs(&self) -> usize {
+        self.root.metrics().egcs
+    }
+
+    /// Returns the number of lines.
+    ///
+    /// It's always one more than the number of line feeds (`\n`).
+    #[inline]
+    pub fn len_lines(&self) -> usize {
+        self.root.metrics().lines + 1
+    }
+
+    //
+
+    /// Returns the byte index of the `char` at the given `char_idx`.
+    ///
+    /// Returns the length in bytes if `char_idx` is the number of `char`s.
+    ///
+    /// # Errors
+    /// Returns an error if `char_idx` > [`len_chars`][Self#method.len_chars].
+    pub fn char_to_byte(&self, char_idx: usize) -> Result<usize> {
+        if char_idx > self.len_chars() {
+            return Err(Error::OutOfBounds);
+        }
+        Ok(self.root.index_to_byte(
+            char_idx,
+            |m| m.chars,
+            |s, i| s.char_indices().nth(i).map_or(s.len(), |(b, _)| b),
+        ))
+    }
+
+    /// Returns the byte index of the extended grapheme cluster at the given `egc_idx`.
+    ///
+    /// Returns the length in bytes if `egc_idx` is the number of clusters.
+    ///
+    /// # Errors
+    /// Returns an error if `egc_idx` > [`len_egcs`][Self#method.len_egcs].
+    pub fn egc_to_byte(&self, egc_idx: usize) -> Result<usize> {
+        if egc_idx > self.len_egcs() {
+            return Err(Error::OutOfBounds);
+        }
+        Ok(self.root.index_to_byte(
+            egc_idx,
+            |m| m.egcs,
+            |s, i| s.grapheme_indices(true).nth(i).map_or(s.len(), |(b, _)| b),
+        ))
+    }
+
+    /// Returns the byte index of the start of the given `line`.
+    ///
+    /// # Errors
+    /// Returns an error if `line` >= [`len_lines`][Self#method.len_lines].
+    pub fn line_to_byte(&self, line: usize) -> Result<usize> {
+        if line >= self.len_lines() {
+            Err(Error::OutOfBounds)
+        } else if line == 0 {
+            Ok(0)
+        } else {
+            Ok(1 + self.root.index_to_byte(
+                line - 1,
+                |m| m.lines,
+                |s, i| s.match_indices('\n').nth(i).map_or(s.len(), |(b, _)| b),
+            ))
+        }
+    }
+
+    /// Returns the index of the `char` that contains the given `byte`.
+    ///
+    /// # Errors
+    /// Returns an error if `byte` > [`len`][Self#method.len].
+    pub fn byte_to_char(&self, byte: usize) -> Result<usize> {
+        self.check_byte(byte)?;
+        Ok(self
+            .root
+            .byte_to_index(byte, |m| m.chars, |s, b| s[..b].chars().count()))
+    }
+
+    /// Returns the index of the extended grapheme cluster that starts at
+    /// or before the given `byte`.
+    ///
+    /// # Errors
+    /// Returns an error if `byte` > [`len`][Self#method.len].
+    pub fn byte_to_egc(&self, byte: usize) -> Result<usize> {
+        self.check_byte(byte)?;
+        Ok(self.root.byte_to_index(
+            byte,
+            |m| m.egcs,
+            |s, b| {
+                let mut starts = s.grapheme_indices(true).map(|(i, _)| i).peekable();
+                let mut before = 0;
+                while starts.next_if(|i| *i < b).is_some() {
+                    before += 1;
+                }
+                // inside a cluster, it's the one that started before
+                if b == s.len() || starts.peek() == Some(&b) {
+                    before
+                } else {
+                    before - 1
+                }
+            },
+        ))
+    }
+
+    /// Returns the index of the line that contains the given `byte`.
+    ///
+    /// # Errors
+    /// Returns an error if `byte` > [`len`][Self#method.len].
+    pub fn byte_to_line(&self, byte: usize) -> Result<usize> {
+        self.check_byte(byte)?;
+        Ok(self
+            .root
+            .byte_to_index(byte, |m| m.lines, |s, b| s[..b].matches('\n').count()))
+    }
+
+    //
+
+    /// Returns the given `line`, without the line ending.
+    ///
+    /// Returns `None` if `line` >= [`len_lines`][Self#method.len_lines].
+    pub fn line(&self, line: usize) -> Option<String> {
+        self.slice(self.line_content(line)?).ok()
+    }
+
+    /// Returns the display width of the given `line`, without the line ending.
+    ///
+    /// It takes *O(log n)* time, since the widths are tracked in the tree.
+    ///
+    /// Returns `None` if `line` >= [`len_lines`][Self#method.len_lines].
+    #[inline]
+    pub fn line_width(&self, line: usize) -> Option<usize> {
+        let end = self.line_content(line)?.end;
+        Some(self.root.column_at(end).0)
+    }
+
+    /// Returns the display column of the given `byte`, from the start of its line.
+    ///
+    /// It takes *O(log n)* time, since the widths are tracked in the tree.
+    ///
+    /// # Errors
+    /// Returns an error if `byte` > [`len`][Self#method.len].
+    pub fn byte_to_column(&self, byte: usize) -> Result<usize> {
+        self.check_byte(byte)?;
+        Ok(self.root.column_at(byte).0)
+    }
+
+    /// Returns the byte index of the extended grapheme cluster displayed at
+    /// the given `column` of the given `line`.
+    ///
+    /// If the column is past the end of the line, it returns the byte index of
+    /// the end of the line, without the line ending.
+    ///
+    /// It takes *O(log n)* time to find the line, plus the time to measure
+    /// its clusters up to the column, without allocating.
+    ///
+    /// Returns `None` if `line` >= [`len_lines`][Self#method.len_lines].
+    pub fn column_to_byte(&self, line: usize, column: usize) -> Option<usize> {
+        let Range { start, end } = self.line_content(line)?;
+        let (mut byte, mut width) = (start, 0);
+        for egc in self.chunks_from(start).flat_map(|c| c.graphemes(true)) {
+            let egc_width = str_width(egc);
+            if byte >= end || width + egc_width > column {
+                return Some(byte.min(end));
+            }
+            width += egc_width;
+            byte += egc.len();
+        }
+        Some(end)
+    }
+
+    //
+
+    /// Returns a new [`String`] with the text in the given `range` of bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the range is out of bounds or if its limits
+    /// are not on `char` boundaries.
+    pub fn slice(&self, range: Range<usize>) -> Result<String> {
+        self.check_range(&range)?;
+        let mut string = String::with_capacity(range.len());
+        for chunk in self.chunks_from(range.start) {
+            let len = chunk.len().min(range.end - range.start - string.len());
+            string.push_str(&chunk[..len]);
+            if string.len() == range.len() {
+                break;
+            }
+        }
+        Ok(string)
+    }
+
+    /// Returns an iterator over the chunks of text stored in the leaves.
+    #[inline]
+    pub fn chunks(&self) -> RopeChunks<'_> {
+        RopeChunks {
+            stack: vec![&self.root],
+        }
+    }
+
+    /// Returns an iterator over the extended grapheme clusters.
+    ///
+    /// Each item will be an error if its cluster doesn't fit in `CAP`.
+    #[inline]
+    pub fn egcs<const CAP: usize>(&self) -> RopeEgcs<'_, CAP> {
+        RopeEgcs {
+            chunks: self.chunks(),
+            egcs: None,
+        }
+    }
+
+    //
+
+    /// Inserts the `text` at the given `byte` index.
+    ///
+    /// # Errors
+    /// Returns [`OutOfBounds`][Error::OutOfBounds] if `byte` > [`len`][Self#method.len],
+    /// or [`NotCharBoundary`][Error::NotCharBoundary] if it's not on a `char` boundary.
+    pub fn insert(&mut self, byte: usize, text: &str) -> Result<()> {
+        self.check_byte(byte)?;
+        if !text.is_empty() {
+            let (leaf, at) = self.root.leaf_at(byte, true);
+            let leaf_end = byte - at + leaf.len() + text.len();
+            let root = mem::take(&mut self.root);
+            self.root = root.edit(byte, true, |s, at| s.insert_str(at, text));
+            self.fix_seam(leaf_end);
+        }
+        Ok(())
+    }
+
+    /// Inserts the `text` at the given `char_idx`.
+    ///
+    /// # Errors
+    /// Returns an error if `char_idx` > [`len_chars`][Self#method.len_chars].
+    #[inline]
+    pub fn insert_at_char(&mut self, char_idx: usize, text: &str) -> Result<()> {
+        self.insert(self.char_to_byte(char_idx)?, text)
+    }
+
+    /// Inserts the `text` at the given `egc_idx`.
+    ///
+    /// # Errors
+    /// Returns an error if `egc_idx` > [`len_egcs`][Self#method.len_egcs].
+    #[inline]
+    pub fn insert_at_egc(&mut self, egc_idx: usize, text: &str) -> Result<()> {
+        self.insert(self.egc_to_byte(egc_idx)?, text)
+    }
+
+    /// Removes the given `range` of bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the range is out of bounds or if its limits
+    /// are not on `char` boundaries.
+    pub fn remove(&mut self, range: Range<usize>) -> Result<()> {
+        self.check_range(&range)?;
+        if range.is_empty() {
+            return Ok(());
+        }
+        let root = mem::take(&mut self.root);
+        let (leaf, at) = root.leaf_at(range.start, false);
+        self.root = if at + range.len() < leaf.len() {
+            root.edit(range.start, false, |s, at| {
+                s.drain(at..at + range.len());
+            })
+        } else {
+            let (left, rest) = root.split(range.start);
+            let (_, right) = rest.split(range.len());
+            Node::join(left, right)
+        };
+        let (leaf, at) = self.root.leaf_at(range.start, false);
+        let leaf_end = range.start - at + leaf.len();
+        self.fix_seam(range.start);
+        self.fix_seam(leaf_end);
+        Ok(())
+    }
+
+    /// Removes the given `range` of `char`s.
+    ///
+    /// # Errors
+    /// Returns an error if the range is out of bounds.
+    #[inline]
+    pub fn remove_chars(&mut self, range: Range<usize>) -> Result<()> {
+        self.remove(self.char_to_byte(range.start)?..self.char_to_byte(range.end)?)
+    }
+
+    /// Removes the given `range` of extended grapheme clusters.
+    ///
+    /// # Errors
+    /// Returns an error if the range is out of bounds.
+    #[inline]
+    pub fn remove_egcs(&mut self, range: Range<usize>) -> Result<()> {
+        self.remove(self.egc_to_byte(range.start)?..self.egc_to_byte(range.end)?)
+    }
+
+    /// Removes all the text.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = Node::default();
+    }
+}
+
+// private helpers
+impl Rope {
+    /// Returns the range of bytes of the given `line`, including the line feed.
+    fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        let start = self.line_to_byte(line).ok()?;
+        let end = self.line_to_byte(line + 1).map_or(self.len(), |b| b - 1);
+        Some(start..end)
+    }
+
+    /// Returns the range of bytes of the given `line`, without the line ending.
+    fn line_content(&self, line: usize) -> Option<Range<usize>> {
+        let Range { start, mut end } = self.line_range(line)?;
+        let (leaf, at) = self.root.leaf_at(end, true);
+        if leaf[..at].ends_with('\r') {
+            end -= 1;
+        }
+        Some(start..end)
+    }
+
+    /// Returns an iterator over the chunks of text from the given `byte`,
+    /// the first one starting at it.
+    fn chunks_from(&self, mut byte: usize) -> impl Iterator<Item = &str> {
+        let mut stack = Vec::new();
+        let mut node = &self.root;
+        while let Node::Branch { left, right, .. } = node {
+            let left_bytes = left.metrics().bytes;
+            if byte < left_bytes {
+                stack.push(&**right);
+                node = left;
+            } else {
+                byte -= left_bytes;
+                node = right;
+            }
+        }
+        let Node::Leaf(first, _) = node else {
+            unreachable!()
+        };
+        core::iter::once(&first[byte..]).chain(RopeChunks { stack })
+    }
+
+    /// Checks that the `byte` is in bounds and on a `char` boundary.
+    fn check_byte(&self, byte: usize) -> Result<()> {
+        if byte > self.len() {
+            return Err(Error::OutOfBounds);
+        }
+        let (leaf, at) = self.root.leaf_at(byte, false);
+        if leaf.is_char_boundary(at) {
+            Ok(())
+        } else {
+            Err(Error::NotCharBoundary { index: byte })
+        }
+    }
+
+    /// Checks that the `range` is in bounds and its limits on `char` boundaries.
+    fn check_range(&self, range: &Range<usize>) -> Result<()> {
+        if range.start > range.end {
+            return Err(Error::OutOfBounds);
+        }
+        self.check_byte(range.start)?;
+        self.check_byte(range.end)
+    }
+
+    /// Makes sure a leaf boundary at `byte` is also a grapheme cluster boundary,
+    /// by moving the start of the right leaf to the end of the left leaf.
+    ///
+    /// The `byte` must be a leaf boundary from before the edit, or the end of
+    /// an edited leaf. If the leaves there were merged, the end of the merged
+    /// leaf is checked instead.
+    ///
+    /// A fixed boundary can change how the rest of the right leaf is segmented,
+    /// like in a run of regional indicators, so the boundary at its end is
+    /// checked next, until one doesn't need to be fixed.
+    fn fix_seam(&mut self, mut byte: usize) {
+        while byte > 0 && byte < self.len() {
+            let (next, at) = self.root.leaf_at(byte, false);
+            if at != 0 {
+                byte += next.len() - at;
+                continue;
+            }
+            let (prev, _) = self.root.leaf_at(byte, true);
+            let last = prev.graphemes(true).next_back().unwrap_or("");
+            let first = next.graphemes(true).next().unwrap_or("");
+
+            let mut seam = String::with_capacity(last.len() + first.len());
+            seam.push_str(last);
+            seam.push_str(first);
+            let seam_len = seam.graphemes(true).next().map_or(0, str::len);
+            if seam_len <= last.len() {
+                return;
+            }
+            let moved_len = seam_len - last.len();
+            let moved = String::from(&next[..moved_len]);
+            let next_end = byte + next.len();
+
+            let root = mem::take(&mut self.root);
+            let root = root.edit(byte, false, |s, at| {
+                s.drain(at..at + moved_len);
+            });
+            self.root = root.edit(byte, true, |s, at| s.insert_str(at, &moved));
+            byte = next_end;
+        }
+    }
+}
+
+/* node */
+
+/// The measures of a node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Metrics {
+    bytes: usize,
+    chars: usize,
+    egcs: usize,
+    lines: usize,       // the number of line feeds
+    first_width: usize, // the display width before the first line feed
+    last_width: usize,  // the display width after the last line feed
+}
+
+impl Metrics {
+    const ZERO: Self = Self {
+        bytes: 0,
+        chars: 0,
+        egcs: 0,
+        lines: 0,
+        first_width: 0,
+        last_width: 0,
+    };
+
+    fn of(string: &str) -> Self {
+        let first = string.find('\n').map_or(string, |i| &string[..i]);
+        let last = string.rfind('\n').map_or(string, |i| &string[i + 1..]);
+        Self {
+            bytes: string.len(),
+            chars: string.chars().count(),
+            egcs: string.graphemes(true).count(),
+            lines: string.bytes().filter(|b| *b == b'\n').count(),
+            first_width: str_width(first),
+            last_width: str_width(last),
+        }
+    }
+
+    // the leaves are split at grapheme cluster boundaries, so the widths add up
+    fn add(self, other: Self) -> Self {
+        Self {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            egcs: self.egcs + other.egcs,
+            lines: self.lines + other.lines,
+            first_width: if self.lines == 0 {
+                self.first_width + other.first_width
+            } else {
+                self.first_width
+            },
+            last_width: if other.lines == 0 {
+                self.last_width + other.last_width
+            } else {
+                other.last_width
+            },
+        }
+    }
+}
+
+/// A node of the rope tree, balanced by height.
+#[derive(Clone, Debug)]
+enum Node {
+    Leaf(String, Metrics),
+    Branch {
+        left: Box<Node>,
+        right: Box<Node>,
+        metrics: Metrics,
+        height: usize,
+    },
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Node::Leaf(String::new(), Metrics::ZERO)
+    }
+}
+
+impl Node {
+    /// Returns a new leaf, or a subtree of leaves if the string is too long.
+    fn from_string(string: String) -> Node {
+        if string.len() <= LEAF_MAX {
+            let metrics = Metrics::of(&string);
+            return Node::Leaf(string, metrics);
+        }
+        let mut chunks = Vec::new();
+        let (mut start, mut prev) = (0, 0);
+        for byte in string
+            .grapheme_indices(true)
+            .map(|(b, _)| b)
+            .chain([string.len()])
+        {
+            if byte - start > LEAF_SPLIT && prev > start {
+                chunks.push(&string[start..prev]);
+                start = prev;
+            }
+            prev = byte;
+        }
+        chunks.push(&string[start..]);
+        Node::build(&chunks)
+    }
+
+    /// Returns a balanced subtree with the given chunks as leaves.
+    fn build(chunks: &[&str]) -> Node {
+        if let [chunk] = chunks {
+            Node::Leaf(String::from(*chunk), Metrics::of(chunk))
+        } else {
+            let (left, right) = chunks.split_at(chunks.len() / 2);
+            Node::branch(Node::build(left), Node::build(right))
+        }
+    }
+
+    /// Returns a new branch, without balancing.
+    fn branch(left: Node, right: Node) -> Node {
+        Node::Branch {
+            metrics: left.metrics().add(right.metrics()),
+            height: 1 + left.height().max(right.height()),
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    fn metrics(&self) -> Metrics {
+        match self {
+            Node::Leaf(_, metrics) => *metrics,
+            Node::Branch { metrics, .. } => *metrics,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            Node::Leaf(..) => 0,
+            Node::Branch { height, .. } => *height,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.metrics().bytes == 0
+    }
+
+    /// Returns the leaf that contains the `byte`, and the index inside it.
+    ///
+    /// If the byte is at a leaf boundary, returns the left leaf if `prefer_left`.
+    fn leaf_at(&self, byte: usize, prefer_left: bool) -> (&str, usize) {
+        match self {
+            Node::Leaf(string, _) => (string, byte),
+            Node::Branch { left, right, .. } => {
+                let left_bytes = left.metrics().bytes;
+                if byte < left_bytes || (prefer_left && byte == left_bytes) || right.is_empty() {
+                    left.leaf_at(byte, prefer_left)
+                } else {
+                    right.leaf_at(byte - left_bytes, prefer_left)
+                }
+            }
+        }
+    }
+
+    /// Returns the display width from the start of the line of the `byte`
+    /// up to it, and whether that line starts inside this node.
+    fn column_at(&self, byte: usize) -> (usize, bool) {
+        match self {
+            Node::Leaf(string, _) => {
+                let prefix = &string[..byte];
+                match prefix.rfind('\n') {
+                    Some(i) => (str_width(&prefix[i + 1..]), true),
+                    None => (str_width(prefix), false),
+                }
+            }
+            Node::Branch { left, right, .. } => {
+                let left_metrics = left.metrics();
+                if byte < left_metrics.bytes {
+                    return left.column_at(byte);
+                }
+                match right.column_at(byte - left_metrics.bytes) {
+                    (column, true) => (column, true),
+                    (column, false) => (left_metrics.last_width + column, left_metrics.lines > 0),
+                }
+            }
+        }
+    }
+
+    /// Converts an index measured by `measure` into a byte index.
+    ///
+    /// The `leaf_byte` closure converts the index inside a leaf.
+    fn index_to_byte(
+        &self,
+        index: usize,
+        measure: fn(Metrics) -> usize,
+        leaf_byte: fn(&str, usize) -> usize,
+    ) -> usize {
+        match self {
+            Node::Leaf(string, _) => leaf_byte(string, index),
+            Node::Branch { left, right, .. } => {
+                let left_index = measure(left.metrics());
+                if index < left_index {
+                    left.index_to_byte(index, measure, leaf_byte)
+                } else {
+                    left.metrics().bytes
+                        + right.index_to_byte(index - left_index, measure, leaf_byte)
+                }
+            }
+        }
+    }
+
+    /// Converts a byte index into an index measured by `measure`.
+    ///
+    /// The `leaf_index` closure converts the byte index inside a leaf.
+    fn byte_to_index(
+        &self,
+        byte: usize,
+        measure: fn(Metrics) -> usize,
+        leaf_index: fn(&str, usize) -> usize,
+    ) -> usize {
+        match self {
+            Node::Leaf(string, _) => leaf_index(string, byte),
+            Node::Branch { left, right, .. } => {
+                let left_bytes = left.metrics().bytes;
+                if byte < left_bytes {
+                    left.byte_to_index(byte, measure, leaf_index)
+                } else {
+                    measure(left.metrics())
+                        + right.byte_to_index(byte - left_bytes, measure, leaf_index)
+                }
+            }
+        }
+    }
+
+    /// Edits the leaf that contains the `byte`, and rebalances the tree.
+    ///
+    /// The `edit` closure receives the leaf string and the index inside it.
+    fn edit<F: FnOnce(&mut String, usize)>(self, byte: usize, prefer_left: bool, edit: F) -> Node {
+        match self {
+            Node::Leaf(mut string, _) => {
+                edit(&mut string, byte);
+                Node::from_string(string)
+            }
+            Node::Branch { left, right, .. } => {
+                let left_bytes = left.metrics().bytes;
+                if byte < left_bytes || (prefer_left && byte == left_bytes) || right.is_empty() {
+                    Node::join(left.edit(byte, prefer_left, edit), *right)
+                } else {
+                    Node::join(*left, right.edit(byte - left_bytes, prefer_left, edit))
+                }
+            }
+        }
+    }
+
+    /// Splits the node in two at the `byte` index.
+    fn split(self, byte: usize) -> (Node, Node) {
+        match self {
+            Node::Leaf(mut string, _) => {
+                let right = string.split_off(byte);
+                (Node::from_string(string), Node::from_string(right))
+            }
+            Node::Branch { left, right, .. } => {
+                let left_bytes = left.metrics().bytes;
+                match byte.cmp(&left_bytes) {
+                    core::cmp::Ordering::Less => {
+                        let (left_left, left_right) = left.split(byte);
+                        (left_left, Node::join(left_right, *right))
+                    }
+                    core::cmp::Ordering::Greater => {
+                        let (right_left, right_right) = right.split(byte - left_bytes);
+                        (Node::join(*left, right_left), right_right)
+                    }
+                    core::cmp::Ordering::Equal => (*left, *right),
+                }
+            }
+        }
+    }
+
+    /// Joins two nodes, keeping the tree balanced.
+    ///
+    /// Small adjacent leaves are merged.
+    fn join(left: Node, right: Node) -> Node {
+        if left.is_empty() {
+            return right;
+        } else if right.is_empty() {
+            return left;
+        }
+        match (left, right) {
+            (Node::Leaf(mut left, _), Node::Leaf(right, _))
+                if left.len() + right.len() <= LEAF_MAX =>
+            {
+                left.push_str(&right);
+                let metrics = Metrics::of(&left);
+                Node::Leaf(left, metrics)
+            }
+            (left, right) => {
+                let (left_height, right_height) = (left.height(), right.height());
+                if left_height > right_height + 1 {
+                    let Node::Branch {
+                        left: ll,
+                        right: lr,
+                        ..
+                    } = left
+                    else {
+                        unreachable!()
+                    };
+                    Node::balance(*ll, Node::join(*lr, right))
+                } else if right_height > left_height + 1 {
+                    let Node::Branch {
+                        left: rl,
+                        right: rr,
+                        ..
+                    } = right
+                    else {
+                        unreachable!()
+                    };
+                    Node::balance(Node::join(left, *rl), *rr)
+                } else {
+                    Node::branch(left, right)
+                }
+            }
+        }
+    }
+
+    /// Returns a new branch, rotating the nodes if their heights differ by 2.
+    fn balance(left: Node, right: Node) -> Node {
+        let (left_height, right_height) = (left.height(), right.height());
+        if left_height > right_height + 1 {
+            let Node::Branch {
+                left: ll,
+                right: lr,
+                ..
+            } = left
+            else {
+                unreachable!()
+            };
+            if ll.height() >= lr.height() {
+                Node::branch(*ll, Node::branch(*lr, right))
+            } else {
+                let Node::Branch {
+                    left: lrl,
+                    right: lrr,
+                    ..
+                } = *lr
+                else {
+                    unreachable!()
+                };
+                Node::branch(Node::branch(*ll, *lrl), Node::branch(*lrr, right))
+            }
+        } else if right_height > left_height + 1 {
+            let Node::Branch {
+                left: rl,
+                right: rr,
+                ..
+            } = right
+            else {
+                unreachable!()
+            };
+            if rr.height() >= rl.height() {
+                Node::branch(Node::branch(left, *rl), *rr)
+            } else {
+                let Node::Branch {
+                    left: rll,
+                    right: rlr,
+                    ..
+                } = *rl
+                else {
+                    unreachable!()
+                };
+                Node::branch(Node::branch(left, *rll), Node::branch(*rlr, *rr))
+            }
+        } else {
+            Node::branch(left, right)
+        }
+    }
+}
+
+/* iterators */
+
+/// An iterator over the chunks of text of a [`Rope`].
+///
+/// This `struct` is created by the [`chunks`][Rope#method.chunks] method.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct RopeChunks<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Iterator for RopeChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some(node) = self.stack.pop() {
+            match node {
+                Node::Leaf(string, _) if !string.is_empty() => return Some(string),
+                Node::Leaf(..) => (),
+                Node::Branch { left, right, .. } => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over the extended grapheme clusters of a [`Rope`].
+///
+/// This `struct` is created by the [`egcs`][Rope#method.egcs] method.
+#[derive(Clone)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct RopeEgcs<'a, const CAP: usize> {
+    chunks: RopeChunks<'a>,
+    egcs: Option<Graphemes<'a>>,
+}
+
+impl<'a, const CAP: usize> Iterator for RopeEgcs<'a, CAP> {
+    type Item = Result<StaticU8Egc<CAP>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(egc) = self.egcs.as_mut().and_then(Iterator::next) {
+                return Some(StaticU8Egc::try_from_str(egc));
+            }
+            self.egcs = Some(self.chunks.next()?.graphemes(true));
+        }
+    }
+}
+
+/* traits */
+
+impl Textual for Rope {}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.chunks()).finish()
+    }
+}
+
+impl From<&str> for Rope {
+    #[inline]
+    fn from(string: &str) -> Rope {
+        Rope::from(String::from(string))
+    }
+}
+
+impl From<String> for Rope {
+    #[inline]
+    fn from(string: String) -> Rope {
+        Rope {
+            root: Node::from_string(string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::width::str_truncate_width;
+    use alloc::string::ToString;
+
+    // a simple deterministic pseudo-random generator
+    fn lcg(seed: &mut u64) -> usize {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (*seed >> 33) as usize
+    }
+
+    #[test]
+    fn edits_match_string() {
+        let pieces = [
+            "a", "ñ", "世界", "\n", "e\u{301}", "\u{301}", "🇪", "\r", "hello ",
+        ];
+        let mut seed = 7;
+        let mut rope = Rope::new();
+        let mut model = String::new();
+
+        for _ in 0..2000 {
+            if lcg(&mut seed) % 3 != 0 || model.is_empty() {
+                let mut text = String::new();
+                for _ in 0..lcg(&mut seed) % 200 {
+                    text.push_str(pieces[lcg(&mut seed) % pieces.len()]);
+                }
+                let at = rope
+                    .char_to_byte(lcg(&mut seed) % (rope.len_chars() + 1))
+                    .unwrap();
+                rope.insert(at, &text).unwrap();
+                model.insert_str(at, &text);
+            } else {
+                let start = lcg(&mut seed) % (rope.len_chars() + 1);
+                let end = (start + lcg(&mut seed) % 300).min(rope.len_chars());
+                rope.remove_chars(start..end).unwrap();
+                let (start, end) = (rope_byte(&model, start), rope_byte(&model, end));
+                model.replace_range(start..end, "");
+            }
+            assert_eq![model.len(), rope.len()];
+        }
+        assert![rope.root.height() > 2];
+        assert_eq![model, rope.to_string()];
+        assert_eq![model.chars().count(), rope.len_chars()];
+        assert_eq![model.graphemes(true).count(), rope.len_egcs()];
+        assert_eq![model.split('\n').count(), rope.len_lines()];
+        let egcs: Vec<_> = rope.egcs::<32>().map(|e| e.unwrap()).collect();
+        assert![egcs.iter().map(|e| e.as_str()).eq(model.graphemes(true))];
+
+        // the tracked widths match measuring the text
+        let mut start = 0;
+        for (i, line) in model.split('\n').enumerate() {
+            let content = line.strip_suffix('\r').unwrap_or(line);
+            assert_eq![Some(str_width(content)), rope.line_width(i)];
+            assert_eq![Some(content.to_string()), rope.line(i)];
+            let end = start + content.len();
+            assert_eq![Some(end), rope.column_to_byte(i, usize::MAX)];
+            let half_column = str_width(content) / 2;
+            let half = str_truncate_width(content, half_column).0;
+            assert_eq![
+                Some(start + half.len()),
+                rope.column_to_byte(i, half_column)
+            ];
+            start += line.len() + 1;
+        }
+        for _ in 0..200 {
+            let byte = rope_byte(&model, lcg(&mut seed) % (rope.len_chars() + 1));
+            let line_start = model[..byte].rfind('\n').map_or(0, |i| i + 1);
+            let column = str_width(&model[line_start..byte]);
+            assert_eq![Some(column), rope.byte_to_column(byte).ok()];
+        }
+
+        // a regional indicator changes how the rest of its run is paired
+        let check = |rope: &Rope, model: &str| {
+            assert_eq![model.graphemes(true).count(), rope.len_egcs()];
+            let egcs: Vec<_> = rope.egcs::<8>().map(|e| e.unwrap()).collect();
+            assert![egcs.iter().map(|e| e.as_str()).eq(model.graphemes(true))];
+            assert_eq![Ok(800), rope.egc_to_byte(100)];
+        };
+        let mut model = "🇺🇸".repeat(1000);
+        let mut rope = Rope::from(model.as_str());
+        rope.insert(0, "🇫").unwrap();
+        model.insert(0, '🇫');
+        check(&rope, &model);
+        rope.insert(rope.len(), "🇫").unwrap();
+        model.push('🇫');
+        check(&rope, &model);
+        rope.remove(0..4).unwrap();
+        model.replace_range(0..4, "");
+        check(&rope, &model);
+
+        fn rope_byte(s: &str, char_idx: usize) -> usize {
+            s.char_indices().nth(char_idx).map_or(s.len(), |(b, _)| b)
+        }
+    }
+
+    #[test]
+    fn lines() {
+        let rope = Rope::from("one\r\ntwo\n\n世界");
+        assert_eq![4, rope.len_lines()];
+        assert_eq![Some("one".to_string()), rope.line(0)];
+        assert_eq![Some("".to_string()), rope.line(2)];
+        assert_eq![Some(4), rope.line_width(3)];
+        assert_eq![None, rope.line(4)];
+        assert_eq![Some(10), rope.line_to_byte(3).ok()];
+        assert_eq![Some(1), rope.byte_to_line(5).ok()];
+        assert_eq![Some(2), rope.byte_to_column(13).ok()];
+        assert_eq![Some(13), rope.column_to_byte(3, 2)];
+        assert_eq![Some(13), rope.column_to_byte(3, 3)];
+    }
+
+    #[test]
+    fn indices() {
+        let rope = Rope::from("ae\u{301}ñ");
+        assert_eq![Some(1), rope.egc_to_byte(1).ok()];
+        assert_eq![Some(4), rope.egc_to_byte(2).ok()];
+        assert_eq![Some(4), rope.char_to_byte(3).ok()];
+        assert_eq![Some(2), rope.byte_to_char(2).ok()];
+        assert_eq![Some(1), rope.byte_to_egc(1).ok()];
+        assert_eq![Some(1), rope.byte_to_egc(2).ok()];
+        assert_eq![Some(2), rope.byte_to_egc(4).ok()];
+        assert_eq![Some(3), rope.byte_to_egc(6).ok()];
+        assert![rope.egc_to_byte(4).is_err()];
+        assert_eq![
+            Err(Error::NotCharBoundary { index: 5 }),
+            rope.byte_to_char(5)
+        ];
+        assert_eq![Err(Error::OutOfBounds), rope.byte_to_char(7)];
+    }
+}
@@ -0,0 +1,388 @@
+// textos::unicode::string::small
+//
+//! `String` stored inline while it fits, or in the heap otherwise.
+//
+// TOC
+// - definitions
+// - trait impls
+// - conversions
+// - tests
+
+use crate::{
+    macros::impl_cmp_str,
+    textual::Textual,
+    unicode::{char::*, string::StaticU8String},
+};
+use alloc::string::String;
+use core::{fmt, ops::Deref};
+
+/* definitions */
+
+/// A UTF-8-encoded string, stored inline in a [`StaticU8String`] of `CAP`
+/// bytes while it fits, that spills to a heap allocated [`String`] otherwise.
+///
+/// Once spilled, it stays in the heap until it's [`clear`][Self#method.clear]ed.
+///
+/// # Size
+/// The inline buffer takes `CAP + 1` bytes, so `SmallString` can only be as
+/// small as a `String` while that fits in the bytes the enum tag leaves free,
+/// like `CAP <= 15` on 64-bit targets. Beyond that it takes `CAP + 1` bytes
+/// plus the tag, rounded up to the alignment of `String`. The exact layout
+/// depends on the compiler.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::string::SmallString;
+///
+/// let mut s = SmallString::<8>::from_char('ñ');
+/// s.push_str("and");
+/// assert![s.is_inline()];
+///
+/// s.push_str("ú and more");
+/// assert![!s.is_inline()];
+/// assert_eq!["ñandú and more", s];
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct SmallString<const CAP: usize>(Repr<CAP>);
+
+#[derive(Clone)]
+enum Repr<const CAP: usize> {
+    Inline(StaticU8String<CAP>),
+    Heap(String),
+}
+
+impl<const CAP: usize> SmallString<CAP> {
+    /// Creates a new empty inline `SmallString`.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(Repr::Inline(StaticU8String::new()))
+    }
+
+    /// Creates a new empty `SmallString` with at least the given `capacity`,
+    /// which will be allocated in the heap if it's greater than `CAP`.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity <= CAP {
+            Self::new()
+        } else {
+            Self(Repr::Heap(String::with_capacity(capacity)))
+        }
+    }
+
+    /// Creates a new `SmallString` from a `Char7`.
+    ///
+    /// # Panic
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub fn from_char7(c: Char7) -> Self {
+        Self::from_char(c.to_char())
+    }
+
+    /// Creates a new `SmallString` from a `Char8`.
+    ///
+    /// # Panic
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub fn from_char8(c: Char8) -> Self {
+        Self::from_char(c.to_char())
+    }
+
+    /// Creates a new `SmallString` from a `Char16`.
+    ///
+    /// # Panic
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub fn from_char16(c: Char16) -> Self {
+        Self::from_char(c.to_char())
+    }
+
+    /// Creates a new `SmallString` from a `Char24`.
+    ///
+    /// # Panic
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub fn from_char24(c: Char24) -> Self {
+        Self::from_char(c.to_char())
+    }
+
+    /// Creates a new `SmallString` from a `Char32`.
+    ///
+    /// # Panic
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub fn from_char32(c: Char32) -> Self {
+        Self::from_char(c.to_char())
+    }
+
+    /// Creates a new `SmallString` from a `char`.
+    ///
+    /// It will be stored in the heap if `CAP` < `c.len_utf8()`.
+    ///
+    /// # Panic
+    /// Panics if `CAP` > 255.
+    #[inline]
+    pub fn from_char(c: char) -> Self {
+        let mut new = Self::new();
+        new.push(c);
+        new
+    }
+
+    //
+
+    /// Returns the length in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns `true` if the current length is 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the current capacity in bytes.
+    ///
+    /// It's `CAP` while it's stored inline.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match &self.0 {
+            Repr::Inline(_) => CAP,
+            Repr::Heap(s) => s.capacity(),
+        }
+    }
+
+    /// Returns `true` if the string is stored inline.
+    #[inline]
+    pub const fn is_inline(&self) -> bool {
+        matches![self.0, Repr::Inline(_)]
+    }
+
+    /// Sets the length to 0, and moves the string back inline.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0 = Repr::Inline(StaticU8String::new());
+    }
+
+    //
+
+    /// Returns the inner string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Inline(s) => s.as_str(),
+            Repr::Heap(s) => s.as_str(),
+        }
+    }
+
+    /// Returns the inner static string, if it's stored inline.
+    #[inline]
+    pub fn as_static(&self) -> Option<&StaticU8String<CAP>> {
+        match &self.0 {
+            Repr::Inline(s) => Some(s),
+            Repr::Heap(_) => None,
+        }
+    }
+
+    /// Converts the string into a heap allocated [`String`].
+    #[inline]
+    pub fn into_string(self) -> String {
+        match self.0 {
+            Repr::Inline(s) => String::from(s.as_str()),
+            Repr::Heap(s) => s,
+        }
+    }
+
+    //
+
+    /// Removes the last character and returns it, or `None` if
+    /// the string is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        match &mut self.0 {
+            Repr::Inline(s) => s.pop(),
+            Repr::Heap(s) => s.pop(),
+        }
+    }
+
+    /// Appends to the end of the string the given `character`,
+    /// moving the string to the heap if it doesn't fit inline.
+    pub fn push(&mut self, character: char) {
+        match &mut self.0 {
+            Repr::Inline(s) => {
+                if s.try_push(character).is_err() {
+                    self.spill(character.len_utf8()).push(character);
+                }
+            }
+            Repr::Heap(s) => s.push(character),
+        }
+    }
+
+    /// Appends to the end of the string the given `string` slice,
+    /// moving the string to the heap if it doesn't fit inline.
+    pub fn push_str(&mut self, string: &str) {
+        match &mut self.0 {
+            Repr::Inline(s) => {
+                if s.try_push_str_complete(string).is_err() {
+                    self.spill(string.len()).push_str(string);
+                }
+            }
+            Repr::Heap(s) => s.push_str(string),
+        }
+    }
+
+    /// Moves the string to the heap, reserving `additional` bytes,
+    /// and returns a reference to it.
+    fn spill(&mut self, additional: usize) -> &mut String {
+        if let Repr::Inline(s) = &self.0 {
+            let mut heap = String::with_capacity(s.len() + additional);
+            heap.push_str(s.as_str());
+            self.0 = Repr::Heap(heap);
+        }
+        match &mut self.0 {
+            Repr::Heap(s) => s,
+            Repr::Inline(_) => unreachable!(),
+        }
+    }
+}
+
+/* traits */
+
+impl_cmp_str![SmallString];
+
+impl<const CAP: usize> Textual for SmallString<CAP> {}
+
+impl<const CAP: usize> Default for SmallString<CAP> {
+    /// Returns an empty inline string.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAP: usize> fmt::Display for SmallString<CAP> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for SmallString<CAP> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl<const CAP: usize> fmt::Write for SmallString<CAP> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl<const CAP: usize> Deref for SmallString<CAP> {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> Extend<char> for SmallString<CAP> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|c| self.push(c));
+    }
+}
+impl<'a, const CAP: usize> Extend<&'a str> for SmallString<CAP> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|s| self.push_str(s));
+    }
+}
+impl<const CAP: usize> FromIterator<char> for SmallString<CAP> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut new = Self::new();
+        new.extend(iter);
+        new
+    }
+}
+
+/* conversions */
+
+impl<const CAP: usize> From<&str> for SmallString<CAP> {
+    /// Stores the `string` inline if it fits, or in the heap otherwise.
+    fn from(string: &str) -> Self {
+        let mut new = Self::with_capacity(string.len());
+        new.push_str(string);
+        new
+    }
+}
+impl<const CAP: usize> From<String> for SmallString<CAP> {
+    /// Stores the `string` inline if it fits, or reuses its allocation otherwise.
+    fn from(string: String) -> Self {
+        if string.len() <= CAP {
+            Self::from(string.as_str())
+        } else {
+            Self(Repr::Heap(string))
+        }
+    }
+}
+impl<const CAP: usize> From<StaticU8String<CAP>> for SmallString<CAP> {
+    #[inline]
+    fn from(string: StaticU8String<CAP>) -> Self {
+        Self(Repr::Inline(string))
+    }
+}
+impl<const CAP: usize> From<SmallString<CAP>> for String {
+    #[inline]
+    fn from(string: SmallString<CAP>) -> String {
+        string.into_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn spill() {
+        let mut s = SmallString::<3>::from_char8(Char8::try_from_char('a').unwrap());
+        s.push('ñ');
+        assert![s.is_inline()];
+        s.push('b');
+        assert![!s.is_inline()];
+        assert_eq![Some('b'), s.pop()];
+        assert![!s.is_inline()];
+        write!(s, "{}", 12).unwrap();
+        assert_eq!["añ12", s];
+
+        s.clear();
+        assert![s.is_inline()];
+        assert_eq![SmallString::<3>::from_char('€'), "€"];
+        assert![!SmallString::<3>::from_char('😀').is_inline()];
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn size() {
+        use core::mem::size_of;
+        assert![size_of::<SmallString<15>>() <= size_of::<String>()];
+    }
+}
@@ -13,8 +13,9 @@ use alloc::{ffi::CString, str::Chars, string::ToString};
 
 use crate::{
     error::{TextosError as Error, TextosResult as Result},
-    macros::impl_sized_alias,
-    unicode::char::*,
+    macros::{impl_cmp_str, impl_sized_alias},
+    textual::Textual,
+    unicode::{char::*, string::impl_str_search},
 };
 use core::{fmt, ops::Deref};
 use devela::codegen::paste;
@@ -25,7 +26,20 @@ use devela::codegen::paste;
 /// with 255 bytes of maximum constant capacity.
 ///
 /// Internally, the current length is stored as a [`u8`].
-#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Comparisons and hashing are based on the string contents, so strings with
+/// different capacities can be compared between them, and with [`str`] slices.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::string::{String64, String128};
+///
+/// let (a, b) = (String64::from_char('a'), String128::from_char('a'));
+/// assert_eq![a, b];
+/// assert_eq![a, "a"];
+/// assert![b < "b"];
+/// ```
+#[derive(Clone, Copy)]
 pub struct StaticU8String<const CAP: usize> {
     // WAITING for when we can use CAP: u8 for panic-less const boundary check.
     arr: [u8; CAP],
@@ -245,6 +259,9 @@ impl<const CAP: usize> StaticU8String<CAP> {
     }
 
     /// Returns a mutable byte slice of the inner string slice.
+    ///
+    /// # Safety
+    /// The content must be valid UTF-8.
     #[inline]
     #[cfg(feature = "unsafe")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe")))]
@@ -297,7 +314,7 @@ impl<const CAP: usize> StaticU8String<CAP> {
     /// Returns an iterator over the `chars` of this grapheme cluster.
     #[cfg(feature = "alloc")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
-    pub fn chars(&self) -> Chars {
+    pub fn chars(&self) -> Chars<'_> {
         self.as_str().chars()
     }
 
@@ -375,10 +392,118 @@ impl<const CAP: usize> StaticU8String<CAP> {
             Err(Error::NotEnoughCapacity(char_len))
         }
     }
+
+    /// Appends to the end the fitting characters from the given `string` slice.
+    ///
+    /// Returns the number of bytes written, which will be 0 if not even the first
+    /// character can fit.
+    pub fn push_str(&mut self, string: &str) -> usize {
+        let mut rem_cap = self.remaining_capacity();
+        let mut bytes_written = 0;
+
+        for c in string.chars() {
+            let char_len = c.len_utf8();
+
+            if char_len <= rem_cap {
+                self.push(c);
+                rem_cap -= char_len;
+                bytes_written += char_len;
+            } else {
+                break;
+            }
+        }
+        bytes_written
+    }
+
+    /// Tries to append to the end the fitting characters from the given `string` slice.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Errors
+    /// Returns an error if the capacity is not enough to hold even the
+    /// first character.
+    pub fn try_push_str(&mut self, string: &str) -> Result<usize> {
+        let first_char_len = string.chars().next().map(|c| c.len_utf8()).unwrap_or(0);
+        if self.remaining_capacity() < first_char_len {
+            Err(Error::NotEnoughCapacity(first_char_len))
+        } else {
+            Ok(self.push_str(string))
+        }
+    }
+
+    /// Tries to append the complete `string` slice to the end.
+    ///
+    /// Returns the number of bytes written in success.
+    ///
+    /// # Errors
+    /// Returns an error if the slice wont completely fit.
+    pub fn try_push_str_complete(&mut self, string: &str) -> Result<usize> {
+        let len = string.len();
+        if self.remaining_capacity() >= len {
+            let beg = self.len as usize;
+            self.arr[beg..beg + len].copy_from_slice(string.as_bytes());
+            self.len += len as u8;
+            Ok(len)
+        } else {
+            Err(Error::NotEnoughCapacity(len))
+        }
+    }
+
+    /// Returns a new string with the [`Display`][fmt::Display] output of `value`.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][Error::NotEnoughCapacity] with the total
+    /// needed capacity if the output doesn't fit.
+    ///
+    /// # Panics
+    /// Panics if `CAP` > 255.
+    ///
+    /// # Examples
+    /// ```
+    /// use textos::unicode::string::StaticU8String;
+    ///
+    /// assert_eq![StaticU8String::<4>::try_from_display(&-123).unwrap(), "-123"];
+    /// assert![StaticU8String::<4>::try_from_display(&12345).is_err()];
+    /// ```
+    pub fn try_from_display<T: fmt::Display + ?Sized>(value: &T) -> Result<Self> {
+        Self::from_render(|w| write!(w, "{value}"))
+    }
+
+    /// Returns a new string with the output of `render`.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughCapacity`][Error::NotEnoughCapacity] with the total
+    /// needed capacity if the output doesn't fit.
+    pub(crate) fn from_render(render: impl Fn(&mut dyn fmt::Write) -> fmt::Result) -> Result<Self> {
+        let mut string = Self::new();
+        if render(&mut string).is_ok() {
+            return Ok(string);
+        }
+        let mut counter = ByteCounter(0);
+        let _ = render(&mut counter);
+        Err(Error::NotEnoughCapacity(counter.0))
+    }
 }
 
+/// A writer that only counts the written bytes.
+struct ByteCounter(usize);
+
+impl fmt::Write for ByteCounter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+impl_str_search![StaticU8String => StaticU8String];
+
 /* traits */
 
+impl_cmp_str![StaticU8String];
+
+impl<const CAP: usize> Textual for StaticU8String<CAP> {}
+
 impl<const CAP: usize> Default for StaticU8String<CAP> {
     /// Returns an empty string.
     ///
@@ -396,6 +521,16 @@ impl<const CAP: usize> fmt::Display for StaticU8String<CAP> {
     }
 }
 
+impl<const CAP: usize> fmt::Write for StaticU8String<CAP> {
+    /// Appends the complete string slice, or fails if it doesn't fit.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str_complete(s)
+            .map(|_| ())
+            .map_err(|_| fmt::Error)
+    }
+}
+
 impl<const CAP: usize> fmt::Debug for StaticU8String<CAP> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -481,4 +616,43 @@ mod tests {
         assert_eq![Some('ñ'), s.pop()];
         assert_eq![None, s.pop()];
     }
+
+    #[test]
+    fn cmp() {
+        let mut a = String32::new();
+        let mut b = String64::new();
+        a.push('a');
+        b.push('a');
+        assert_eq![a, b];
+        assert_eq![a, "a"];
+        assert_eq!["a", b];
+
+        b.push('b');
+        assert![a < b];
+        assert![b > "a"];
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_borrow() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert(String64::from_char('a'), 1);
+        assert_eq![Some(&1), map.get("a")];
+    }
+
+    #[test]
+    fn search() {
+        let mut s = String128::new();
+        s.push_str("a,b,,c");
+
+        assert_eq![Some(1), s.find(',')];
+        assert_eq![Some(4), s.rfind(",")];
+        assert![s.split(',').eq(["a", "b", "", "c"])];
+        assert![s.splitn(2, ",").eq(["a", "b,,c"])];
+        assert_eq!["a;b;;c", s.replace(',', ";").unwrap()];
+        assert_eq!["a--b,,c", s.replacen(",", "--", 1).unwrap()];
+        assert![s.replace(',', "------").is_err()];
+    }
 }
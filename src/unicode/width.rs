@@ -0,0 +1,73 @@
+// textos::unicode::width
+//
+//! Display width.
+//!
+//! The width of the text when displayed in a monospaced terminal,
+//! according to the [Unicode Standard Annex #11][0] rules.
+//!
+//! [0]: https://www.unicode.org/reports/tr11/
+//
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Returns the display width of the given `string` slice.
+///
+/// Each extended grapheme cluster is measured as a whole, so combining marks
+/// and emoji sequences don't add to the width.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::width::str_width;
+///
+/// assert_eq![5, str_width("hello")];
+/// assert_eq![4, str_width("漢字")];
+/// assert_eq![1, str_width("e\u{301}")];
+/// assert_eq![2, str_width("👩\u{200D}👩\u{200D}👧")];
+/// ```
+#[inline]
+pub fn str_width(string: &str) -> usize {
+    string.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+/// Returns the display width of the first extended grapheme cluster
+/// of the given `string` slice.
+///
+/// Returns 0 if the string is empty.
+#[inline]
+pub fn egc_width(string: &str) -> usize {
+    string
+        .graphemes(true)
+        .next()
+        .map_or(0, UnicodeWidthStr::width)
+}
+
+/// Returns the display width of the given `character`.
+///
+/// Control characters have a width of 0.
+#[inline]
+pub fn char_width(character: char) -> usize {
+    UnicodeWidthChar::width(character).unwrap_or(0)
+}
+
+/// Returns the longest prefix of the given `string` slice that fits in
+/// `max_width`, without splitting extended grapheme clusters, and its width.
+///
+/// # Examples
+/// ```
+/// use textos::unicode::width::str_truncate_width;
+///
+/// assert_eq![("ab", 2), str_truncate_width("abc", 2)];
+/// assert_eq![("漢", 2), str_truncate_width("漢字", 3)];
+/// ```
+pub fn str_truncate_width(string: &str, max_width: usize) -> (&str, usize) {
+    let mut width = 0;
+    for (byte, egc) in string.grapheme_indices(true) {
+        let egc_width = UnicodeWidthStr::width(egc);
+        if width + egc_width > max_width {
+            return (&string[..byte], width);
+        }
+        width += egc_width;
+    }
+    (string, width)
+}